@@ -0,0 +1,260 @@
+use crate::chacha8::qr;
+use crate::error::RandError;
+use crate::reader;
+use crate::source::Source;
+use crate::splitmix::SeedExpander;
+
+/// A ChaCha20Source produces the ChaCha20 keystream for its key with a
+/// zero nonce, one 32-bit word at a time.
+///
+/// It uses Bernstein's original layout, a 64-bit block counter in
+/// state words 12 and 13 followed by a 64-bit nonce, rather than the
+/// 32-bit counter and 96-bit nonce of RFC 8439. With a zero nonce the
+/// two agree for the first 2^32 blocks (256 GiB), so the RFC test
+/// vectors apply; after that the counter carries into word 13 instead
+/// of wrapping, which gives a period of 2^70 bytes.
+///
+/// Like `ChaCha8Source` it is cryptographically strong when keyed from
+/// the system's source of randomness; it trades speed for the full
+/// twenty rounds.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ChaCha20State")
+)]
+pub struct ChaCha20Source {
+    key: [u32; 8],
+    counter: u64,
+    buf: [u32; 16],
+    i: usize,
+    // The word fill_bytes is taking bytes from, and how many of its
+    // bytes are left.
+    read_val: u32,
+    read_pos: u8,
+}
+
+impl ChaCha20Source {
+    /// Creates a new ChaCha20Source with key, positioned at block 0.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{ChaCha20Source, Rand};
+    ///
+    /// fn main() {
+    ///     let mut r: Rand<_> = Rand::new(ChaCha20Source::new([7; 32]));
+    ///     println!("n: {}", r.uint64());
+    /// }
+    /// ```
+    pub fn new(key: [u8; 32]) -> ChaCha20Source {
+        let mut src = ChaCha20Source {
+            key: [0; 8],
+            counter: 0,
+            buf: [0; 16],
+            i: 16,
+            read_val: 0,
+            read_pos: 0,
+        };
+        src.init(key);
+        src
+    }
+
+    /// Creates a new ChaCha20Source keyed from the system's source of
+    /// randomness.
+    pub fn from_entropy() -> Result<ChaCha20Source, RandError> {
+        let mut key = [0u8; 32];
+        reader::read(&mut key)?;
+        Ok(ChaCha20Source::new(key))
+    }
+
+    fn init(&mut self, key: [u8; 32]) {
+        for (i, word) in self.key.iter_mut().enumerate() {
+            let mut b = [0u8; 4];
+            b.copy_from_slice(&key[i * 4..i * 4 + 4]);
+            *word = u32::from_le_bytes(b);
+        }
+        self.counter = 0;
+        self.i = 16;
+        self.read_pos = 0;
+    }
+}
+
+impl Source for ChaCha20Source {
+    /// Expands seed into a full 32-byte key and resets the generator
+    /// as if by `ChaCha20Source::new` with that key.
+    fn seed(&mut self, seed: i64) {
+        let mut key = [0u8; 32];
+        SeedExpander::new(seed as u64).fill_bytes(&mut key);
+        self.init(key);
+    }
+
+    fn uint32(&mut self) -> u32 {
+        if self.i >= 16 {
+            self.buf = block(
+                &self.key,
+                [self.counter as u32, (self.counter >> 32) as u32, 0, 0],
+            );
+            self.counter = self.counter.wrapping_add(1);
+            self.i = 0;
+        }
+        let x = self.buf[self.i];
+        self.i += 1;
+        x
+    }
+
+    fn uint64(&mut self) -> u64 {
+        let lo = self.uint32() as u64;
+        lo | (self.uint32() as u64) << 32
+    }
+
+    fn int64(&mut self) -> i64 {
+        (self.uint64() & !(1 << 63)) as i64
+    }
+
+    /// Fills buf with the keystream, four bytes per word. The unused
+    /// bytes of the last word are kept for the next call, so consecutive
+    /// calls produce the RFC 8439 keystream however they are split.
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        for b in buf.iter_mut() {
+            if self.read_pos == 0 {
+                self.read_val = self.uint32();
+                self.read_pos = 4;
+            }
+            *b = self.read_val as u8;
+            self.read_val >>= 8;
+            self.read_pos -= 1;
+        }
+    }
+}
+
+/// Computes the ChaCha20 block for key, where input holds the last
+/// four words of the state: the block counter and the nonce.
+fn block(key: &[u32; 8], input: [u32; 4]) -> [u32; 16] {
+    let mut state: [u32; 16] = [
+        // "expand 32-byte k"
+        0x61707865, 0x3320646e, 0x79622d32, 0x6b206574, key[0], key[1], key[2], key[3], key[4],
+        key[5], key[6], key[7], input[0], input[1], input[2], input[3],
+    ];
+    let init = state;
+    let s = &mut state;
+    for _round in 0..10 {
+        // Column round.
+        (s[0], s[4], s[8], s[12]) = qr(s[0], s[4], s[8], s[12]);
+        (s[1], s[5], s[9], s[13]) = qr(s[1], s[5], s[9], s[13]);
+        (s[2], s[6], s[10], s[14]) = qr(s[2], s[6], s[10], s[14]);
+        (s[3], s[7], s[11], s[15]) = qr(s[3], s[7], s[11], s[15]);
+        // Diagonal round.
+        (s[0], s[5], s[10], s[15]) = qr(s[0], s[5], s[10], s[15]);
+        (s[1], s[6], s[11], s[12]) = qr(s[1], s[6], s[11], s[12]);
+        (s[2], s[7], s[8], s[13]) = qr(s[2], s[7], s[8], s[13]);
+        (s[3], s[4], s[9], s[14]) = qr(s[3], s[4], s[9], s[14]);
+    }
+    for (x, y) in state.iter_mut().zip(init.iter()) {
+        *x = x.wrapping_add(*y);
+    }
+    state
+}
+
+/// The serialized form of a ChaCha20Source, checked before use.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct ChaCha20State {
+    key: [u32; 8],
+    counter: u64,
+    buf: [u32; 16],
+    i: usize,
+    read_val: u32,
+    read_pos: u8,
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<ChaCha20State> for ChaCha20Source {
+    type Error = RandError;
+
+    fn try_from(s: ChaCha20State) -> Result<ChaCha20Source, RandError> {
+        if s.i > 16 || s.read_pos > 4 {
+            return Err(RandError::InvalidArgument("invalid ChaCha20Source state"));
+        }
+        Ok(ChaCha20Source {
+            key: s.key,
+            counter: s.counter,
+            buf: s.buf,
+            i: s.i,
+            read_val: s.read_val,
+            read_pos: s.read_pos,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{block, ChaCha20Source};
+    use crate::rand::Rand;
+    use crate::source::Source;
+    use std::io::Read;
+
+    fn words(key: [u8; 32]) -> [u32; 8] {
+        let mut w = [0u32; 8];
+        for (i, word) in w.iter_mut().enumerate() {
+            *word =
+                u32::from_le_bytes([key[4 * i], key[4 * i + 1], key[4 * i + 2], key[4 * i + 3]]);
+        }
+        w
+    }
+
+    #[test]
+    fn examples() {
+        // RFC 8439, section 2.3.2.
+        let mut key = [0u8; 32];
+        for (i, b) in key.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        let expected: [u32; 16] = [
+            0xe4e7f110, 0x15593bd1, 0x1fdd0f50, 0xc47120a3, 0xc7f4d1c7, 0x0368c033, 0x9aaa2204,
+            0x4e6cd4c3, 0x466482d2, 0x09aa9f07, 0x05d7c214, 0xa2028bd9, 0xd19c12b5, 0xb94e16de,
+            0xe883d0cb, 0x4e3c50a2,
+        ];
+        assert_eq!(expected, block(&words(key), [1, 0x09000000, 0x4a000000, 0]));
+
+        // RFC 8439, appendix A.1, test vectors 1 and 2: the all-zero key
+        // at blocks 0 and 1.
+        let expected: Vec<u32> = vec![
+            0xade0b876, 0x903df1a0, 0xe56a5d40, 0x28bd8653, 0xb819d2bd, 0x1aed8da0, 0xccef36a8,
+            0xc70d778b, 0x7c5941da, 0x8d485751, 0x3fe02477, 0x374ad8b8, 0xf4b8436a, 0x1ca11815,
+            0x69b687c3, 0x8665eeb2, 0xbee7079f, 0x7a385155, 0x7c97ba98, 0x0d082d73, 0xa0290fcb,
+            0x6965e348, 0x3e53c612, 0xed7aee32, 0x7621b729, 0x434ee69c, 0xb03371d5, 0xd539d874,
+            0x281fed31, 0x45fb0a51, 0x1f0ae1ac, 0x6f4d794b,
+        ];
+        let mut src = ChaCha20Source::new([0; 32]);
+        let mut get = vec![];
+        for _i in 0..32 {
+            get.push(src.uint32());
+        }
+        assert_eq!(expected, get);
+
+        let mut bytes = [0u8; 8];
+        ChaCha20Source::new([0; 32]).fill_bytes(&mut bytes);
+        assert_eq!([0x76, 0xb8, 0xe0, 0xad, 0xa0, 0xf1, 0x3d, 0x90], bytes);
+
+        // Split reads continue where the last one ended.
+        for split in 0..=8 {
+            let mut src = ChaCha20Source::new([0; 32]);
+            let mut get = [0u8; 8];
+            let (a, b) = get.split_at_mut(split);
+            src.fill_bytes(a);
+            src.fill_bytes(b);
+            assert_eq!(bytes, get);
+        }
+
+        // Reading through a Rand gives the keystream of test vectors 1
+        // and 2 too.
+        let keystream: Vec<u8> = expected.iter().flat_map(|w| w.to_le_bytes()).collect();
+        let mut r: Rand<_> = Rand::new(ChaCha20Source::new([0; 32]));
+        let mut get = vec![0u8; 128];
+        let (a, b) = get.split_at_mut(13);
+        r.read_exact(a).unwrap();
+        r.read_exact(b).unwrap();
+        assert_eq!(keystream, get);
+    }
+}
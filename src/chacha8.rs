@@ -0,0 +1,1261 @@
+use crate::error::RandError;
+use crate::reader;
+use crate::source::Source;
+use crate::splitmix::SeedExpander;
+
+// increment counter by 4 between block calls
+const CTR_INC: u32 = 4;
+// reseed when counter reaches 16
+const CTR_MAX: u32 = 16;
+// each chunk produced by block is 32 u64s
+const CHUNK: usize = 32;
+// reseed with 4 words
+const RESEED: usize = 4;
+
+/// A ChaCha8Source is a ChaCha8-based cryptographically strong
+/// random number generator.
+///
+/// It produces the same stream as `ChaCha8` from Go's math/rand/v2,
+/// which is specified at https://c2sp.org/chacha8rand.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ChaCha8State")
+)]
+pub struct ChaCha8Source {
+    buf: [u64; CHUNK],
+    seed: [u64; 4],
+    i: usize,
+    n: usize,
+    c: u32,
+}
+
+impl ChaCha8Source {
+    /// Creates a new ChaCha8Source seeded with the given seed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::ChaCha8Source;
+    ///
+    /// fn main() {
+    ///     let src = ChaCha8Source::new(*b"ABCDEFGHIJKLMNOPQRSTUVWXYZ123456");
+    /// }
+    /// ```
+    pub fn new(seed: [u8; 32]) -> ChaCha8Source {
+        let mut src = ChaCha8Source {
+            buf: [0; CHUNK],
+            seed: [0; 4],
+            i: 0,
+            n: 0,
+            c: 0,
+        };
+        src.init(seed);
+        src
+    }
+
+    /// Creates a new ChaCha8Source seeded from the system's source of
+    /// randomness.
+    pub fn from_entropy() -> Result<ChaCha8Source, RandError> {
+        let mut seed = [0u8; 32];
+        reader::read(&mut seed)?;
+        Ok(ChaCha8Source::new(seed))
+    }
+
+    fn init(&mut self, seed: [u8; 32]) {
+        for (i, word) in self.seed.iter_mut().enumerate() {
+            let mut b = [0u8; 8];
+            b.copy_from_slice(&seed[i * 8..i * 8 + 8]);
+            *word = u64::from_le_bytes(b);
+        }
+        block(&self.seed, &mut self.buf, 0);
+        self.c = 0;
+        self.i = 0;
+        self.n = CHUNK;
+    }
+
+    fn refill(&mut self) {
+        self.c += CTR_INC;
+        if self.c == CTR_MAX {
+            // Reseed with generated u64s for forward secrecy.
+            // Normally this is done immediately after computing a block,
+            // but we do it immediately before computing the next block,
+            // to allow a much smaller serialized state (just the seed plus offset).
+            self.seed.copy_from_slice(&self.buf[CHUNK - RESEED..]);
+            self.c = 0;
+        }
+        block(&self.seed, &mut self.buf, self.c);
+        self.i = 0;
+        self.n = CHUNK;
+        if self.c == CTR_MAX - CTR_INC {
+            self.n = CHUNK - RESEED;
+        }
+    }
+}
+
+impl Source for ChaCha8Source {
+    /// Expands seed into a full 32-byte key and resets the generator
+    /// as if by `ChaCha8Source::new` with that key.
+    fn seed(&mut self, seed: i64) {
+        let mut key = [0u8; 32];
+        SeedExpander::new(seed as u64).fill_bytes(&mut key);
+        self.init(key);
+    }
+
+    fn uint64(&mut self) -> u64 {
+        loop {
+            if self.i < self.n {
+                let x = self.buf[self.i];
+                self.i += 1;
+                return x;
+            }
+            self.refill();
+        }
+    }
+
+    fn int64(&mut self) -> i64 {
+        (self.uint64() & !(1 << 63)) as i64
+    }
+}
+
+pub(crate) fn qr(a: u32, b: u32, c: u32, d: u32) -> (u32, u32, u32, u32) {
+    let a = a.wrapping_add(b);
+    let d = (d ^ a).rotate_left(16);
+    let c = c.wrapping_add(d);
+    let b = (b ^ c).rotate_left(12);
+    let a = a.wrapping_add(b);
+    let d = (d ^ a).rotate_left(8);
+    let c = c.wrapping_add(d);
+    let b = (b ^ c).rotate_left(7);
+    (a, b, c, d)
+}
+
+/// Computes 4 ChaCha8 blocks for counters counter..counter+4 into buf.
+///
+/// The blocks are interlaced the same way they would be in a 4-way
+/// SIMD implementation: word w of block i is the u32 at index 4*w+i
+/// when buf is read as little-endian u32s.
+fn block(seed: &[u64; 4], buf: &mut [u64; CHUNK], counter: u32) {
+    let mut lanes = [[0u32; 16]; 4];
+    for (i, out) in lanes.iter_mut().enumerate() {
+        let input: [u32; 16] = [
+            // Constants; same as in ChaCha20: "expand 32-byte k"
+            0x61707865,
+            0x3320646e,
+            0x79622d32,
+            0x6b206574,
+            // Seed values.
+            seed[0] as u32,
+            (seed[0] >> 32) as u32,
+            seed[1] as u32,
+            (seed[1] >> 32) as u32,
+            seed[2] as u32,
+            (seed[2] >> 32) as u32,
+            seed[3] as u32,
+            (seed[3] >> 32) as u32,
+            // Counter.
+            counter + i as u32,
+            // Zeros.
+            0,
+            0,
+            0,
+        ];
+        let mut x = input;
+        // 4 iterations of eight quarter-rounds each is 8 rounds
+        for _round in 0..4 {
+            (x[0], x[4], x[8], x[12]) = qr(x[0], x[4], x[8], x[12]);
+            (x[1], x[5], x[9], x[13]) = qr(x[1], x[5], x[9], x[13]);
+            (x[2], x[6], x[10], x[14]) = qr(x[2], x[6], x[10], x[14]);
+            (x[3], x[7], x[11], x[15]) = qr(x[3], x[7], x[11], x[15]);
+
+            (x[0], x[5], x[10], x[15]) = qr(x[0], x[5], x[10], x[15]);
+            (x[1], x[6], x[11], x[12]) = qr(x[1], x[6], x[11], x[12]);
+            (x[2], x[7], x[8], x[13]) = qr(x[2], x[7], x[8], x[13]);
+            (x[3], x[4], x[9], x[14]) = qr(x[3], x[4], x[9], x[14]);
+        }
+        // Add x4..x11 back to the original key material,
+        // like in ChaCha20, to avoid trivial invertibility.
+        // There is no entropy in x0..x3 and x12..x15
+        // so we can skip the additions and save some time.
+        for w in 4..12 {
+            x[w] = x[w].wrapping_add(input[w]);
+        }
+        *out = x;
+    }
+
+    for (k, out) in buf.iter_mut().enumerate() {
+        let lo = lanes[(k % 2) * 2][k / 2];
+        let hi = lanes[(k % 2) * 2 + 1][k / 2];
+        *out = lo as u64 | (hi as u64) << 32;
+    }
+}
+
+/// The serialized form of a ChaCha8Source, checked before use.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct ChaCha8State {
+    buf: [u64; CHUNK],
+    seed: [u64; 4],
+    i: usize,
+    n: usize,
+    c: u32,
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<ChaCha8State> for ChaCha8Source {
+    type Error = RandError;
+
+    fn try_from(s: ChaCha8State) -> Result<ChaCha8Source, RandError> {
+        let n = if s.c == CTR_MAX - CTR_INC {
+            CHUNK - RESEED
+        } else {
+            CHUNK
+        };
+        if !s.c.is_multiple_of(CTR_INC) || s.c >= CTR_MAX || s.n != n || s.i > s.n {
+            return Err(RandError::InvalidArgument("invalid ChaCha8Source state"));
+        }
+        Ok(ChaCha8Source {
+            buf: s.buf,
+            seed: s.seed,
+            i: s.i,
+            n: s.n,
+            c: s.c,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ChaCha8Source;
+    use crate::source::Source;
+
+    #[test]
+    fn examples() {
+        let mut c = ChaCha8Source::new(*b"ABCDEFGHIJKLMNOPQRSTUVWXYZ123456");
+        let mut get = vec![];
+        for _i in 0..OUTPUT.len() {
+            get.push(c.uint64());
+        }
+        assert_eq!(OUTPUT.to_vec(), get);
+
+        let mut c = ChaCha8Source::new([0; 32]);
+        c.uint64();
+        let mut d = c.clone();
+        for _i in 0..200 {
+            assert_eq!(c.uint64(), d.uint64());
+        }
+
+        let mut c = ChaCha8Source::new([0; 32]);
+        let mut d = ChaCha8Source::new([1; 32]);
+        c.seed(1);
+        d.seed(1);
+        for _i in 0..200 {
+            assert_eq!(c.uint64(), d.uint64());
+        }
+    }
+
+    // The output of Go's chacha8rand for the seed used in its tests.
+    const OUTPUT: [u64; 1000] = [
+        0xb773b6063d4616a5,
+        0x1160af22a66abc3c,
+        0x8c2599d9418d287c,
+        0x7ee07e037edc5cd6,
+        0xcfaa9ee02d1c16ad,
+        0xe090eef8febea79,
+        0x3c82d271128b5b3e,
+        0x9c5addc11252a34f,
+        0xdf79bb617d6ceea6,
+        0x36d553591f9d736a,
+        0xeef0d14e181ee01f,
+        0x89bfc760ae58436,
+        0xd9e52b59cc2ad268,
+        0xeb2fb4444b1b8aba,
+        0x4f95c8a692c46661,
+        0xc3c6323217cae62c,
+        0x91ebb4367f4e2e7e,
+        0x784cf2c6a0ec9bc6,
+        0x5c34ec5c34eabe20,
+        0x4f0a8f515570daa8,
+        0xfc35dcb4113d6bf2,
+        0x5b0da44c645554bc,
+        0x6d963da3db21d9e1,
+        0xeeaefc3150e500f3,
+        0x2d37923dda3750a5,
+        0x380d7a626d4bc8b0,
+        0xeeaf68ede3d7ee49,
+        0xf4356695883b717c,
+        0x846a9021392495a4,
+        0x8e8510549630a61b,
+        0x18dc02545dbae493,
+        0xf8f9ff0a65a3d43,
+        0xccf065f7190ff080,
+        0xfd76d1aa39673330,
+        0x95d232936cba6433,
+        0x6c7456d1070cbd17,
+        0x462acfdaff8c6562,
+        0x5bafab866d34fc6a,
+        0xc862f78030a2988,
+        0xd39a83e407c3163d,
+        0xc00a2b7b45f22ebf,
+        0x564307c62466b1a9,
+        0x257e0424b0c072d4,
+        0x6fb55e99496c28fe,
+        0xae9873a88f5cd4e0,
+        0x4657362ac60d3773,
+        0x1c83f91ecdf23e8e,
+        0x6fdc0792c15387c0,
+        0x36dad2a30dfd2b5c,
+        0xa4b593290595bdb7,
+        0x4de18934e4cc02c5,
+        0xcdc0d604f015e3a7,
+        0xfba0dbf69ad80321,
+        0x60e8bea3d139de87,
+        0xd18a4d851ef48756,
+        0x6366447c2215f34a,
+        0x5682e97d3d007ee,
+        0x4c0e8978c6d54ab2,
+        0xcf1e9f6a6712edc2,
+        0x61439414c80cfd3,
+        0xd1a8b6e2745c0ead,
+        0x31a7918d45c410e8,
+        0xabcc61ad90216eec,
+        0x4040d92d2032a71a,
+        0x3cd2f66ffb40cd68,
+        0xdcd051c07295857a,
+        0xeab55cbcd9ab527e,
+        0x18471dce781bdaac,
+        0xf7f08cd144dc7252,
+        0x5804e0b13d7f40d1,
+        0x5cb1a446e4b2d35b,
+        0xe6d4a728d2138a06,
+        0x5223e40ca60dad8,
+        0x2d61ec3206ac6a68,
+        0xab692356874c17b8,
+        0xc30954417676de1c,
+        0x4f1ace3732225624,
+        0xfba9510813988338,
+        0x997f200f52752e11,
+        0x1116aaafe86221fa,
+        0x7ce3b5cb2a13519,
+        0x2956bc72bc458314,
+        0x4188b7926140eb78,
+        0x56ca6dbfd4adea4d,
+        0x7fe3c22349340ce5,
+        0x35c08f9c37675f8a,
+        0x11e1c7fbef5ed521,
+        0x98adc8464ec1bc75,
+        0xd163b2c73d1203f8,
+        0x8c761ee043a2f3f3,
+        0x24b99d6accecd7b7,
+        0x793e31aa112f0370,
+        0x8e87dc2a19285139,
+        0x4247ae04f7096e25,
+        0x514f3122926fe20f,
+        0xdc6fb3f045d2a7e9,
+        0x15cb30cecdd18eba,
+        0xcbc7fdecf6900274,
+        0x3fb5c696dc8ba021,
+        0xd1664417c8d274e6,
+        0x5f7e445ea457278,
+        0xf920bbca1b9db657,
+        0xc1950b4da22cb99,
+        0xf875baf1af09e292,
+        0xbed3d7b84250f838,
+        0xf198e8080fd74160,
+        0xc9eda51d9b7ea703,
+        0xf709ef55439bf8f6,
+        0xd20c74feebf116fc,
+        0x305668eb146d7546,
+        0x829af3ec10d89787,
+        0x15b8f9697b551dbc,
+        0xfc823c6c8e64b8c9,
+        0x345585e8183b40bc,
+        0x674b4171d6581368,
+        0x1234d81cd670e9f7,
+        0xe505210d8a55e19,
+        0xe8258d69eeeca0dc,
+        0x5d4c452e8baf67e,
+        0xe8dbe30116a45599,
+        0x1cf08ce1b1176f00,
+        0xccf7d0a4b81ecb49,
+        0x303fea136b2c430e,
+        0x861d6c139c06c871,
+        0x5f41df72e05e0487,
+        0x25bd7e1e1ae26b1d,
+        0xbe9f4004d662a41d,
+        0x65bf58d483188546,
+        0xd1b27cff69db13cc,
+        0x1a6663372c1bb36,
+        0x578dd7577b727f4d,
+        0x19c78f066c083cf6,
+        0xdbe014d4f9c391bb,
+        0x97fbb2dd1d13ffb3,
+        0x31c91e0af9ef8d4f,
+        0x94dfc98402a43ba,
+        0x69bd61bea37b752,
+        0x5b72d762e8d986ca,
+        0x72ee31865904bc85,
+        0xd1f5fdc5cd36c33e,
+        0xba9b4980a8947cad,
+        0xece8f05eac49ab43,
+        0x65fe1184abae38e7,
+        0x2d7cb9dea5d31452,
+        0xcc71489476e467e3,
+        0x4c03a258a578c68c,
+        0xefdf9ecb0fd8fc,
+        0x9924cad471e2666d,
+        0x87f8668318f765e9,
+        0xcb4dc57c1b55f5d8,
+        0xd373835a86604859,
+        0xe526568b5540e482,
+        0x1f39040f08586fec,
+        0xb764f3f00293f8e6,
+        0x49443a2f6bd50a8,
+        0x76fec88697d3941a,
+        0x3efb70d039bae7a2,
+        0xe2f4611368eca8a8,
+        0x7c007a96e01d2425,
+        0xbbcce5768e69c5bf,
+        0x784fb4985c42aac3,
+        0xf72b5091aa223874,
+        0x3630333fb1e62e07,
+        0x8e7319ebdebbb8de,
+        0x2a3982bca959fa00,
+        0xb2b98b9f964ba9b3,
+        0xf7e31014adb71951,
+        0xebd0fca3703acc82,
+        0xec654e2a2fe6419a,
+        0xb326132d55a52e2c,
+        0x2248c57f44502978,
+        0x32710c2f342daf16,
+        0x517b47b5acb2bec,
+        0x4c7a718fca270937,
+        0xd69142bed0bcc541,
+        0xe40ebcb8ff52ce88,
+        0x3e44a2dbc9f828d4,
+        0xc74c2f4f8f873f58,
+        0x3dbf648eb799e45b,
+        0x33f22475ee0e86f8,
+        0x1eb4f9ee16d47f65,
+        0x40f8d2b8712744e3,
+        0xb886b4da3cb14572,
+        0x2086326fbdd6f64d,
+        0xcc3de5907dd882b9,
+        0xa2e8b49a5ee909df,
+        0xdbfb8e7823964c10,
+        0x70dd6089ef0df8d5,
+        0x30141663cdd9c99f,
+        0x4b805325c240365,
+        0x7483d80314ac12d6,
+        0x2b271cb91aa7f5f9,
+        0x97e2245362abddf0,
+        0x5a84f614232a9fab,
+        0xf71125fcda4b7fa2,
+        0x1ca5a61d74b27267,
+        0x38cc6a9b3adbcb45,
+        0xdde1bb85dc653e39,
+        0xe9d0c8fa64f89fd4,
+        0x2c5fb1ecd2b4188,
+        0xf2bd137bca5756e5,
+        0xadefe25d121be155,
+        0x56cd1c3c5d893a8e,
+        0x4c50d337beb65bb9,
+        0x918c5151675cf567,
+        0xaba649ffcfb56a1e,
+        0x20c74ab26a2247cd,
+        0x71166bac853c08da,
+        0xb07befe2e584fc5d,
+        0xda45ff2a588dbf32,
+        0xdb98b03c4d75095e,
+        0x60285ae1aaa65a4c,
+        0xf93b686a263140b8,
+        0xde469752ee1c180e,
+        0xcec232dc04129aae,
+        0xeb916baa1835ea04,
+        0xd49c21c8b64388ff,
+        0x72a82d9658864888,
+        0x3348ef7eac66a8,
+        0x7f6f67e655b209eb,
+        0x532ffb0b7a941b25,
+        0xd940ade6128deede,
+        0xdf24f2a1af89fe23,
+        0x95aa3b4988195ae0,
+        0x3da649404f94be4a,
+        0x692dad132c3f7e27,
+        0x40aee76ecaaa9eb8,
+        0x1294a01e09655024,
+        0x6df797abdba4e4f5,
+        0xea2fb6024c1d7032,
+        0x5f4e0492295489fc,
+        0x57972914ea22e06a,
+        0x9a8137d133aad473,
+        0xa2e6dd6ae7cdf2f3,
+        0x9f42644f18086647,
+        0x16d03301c170bd3e,
+        0x908c416fa546656d,
+        0xe081503be22e123e,
+        0x77cf09116c4cc72,
+        0xcbd25cd264b7f229,
+        0x3db2f468ec594031,
+        0x46c00e734c9badd5,
+        0xd0ec0ac72075d861,
+        0x3037cb3cf80b7630,
+        0x574c3d7b3a2721c6,
+        0xae99906a0076824b,
+        0xb175a5418b532e70,
+        0xd8b3e251ee231ddd,
+        0xb433eec25dca1966,
+        0x530f30dc5cff9a93,
+        0x9ff03d98b53cd335,
+        0xafc4225076558cdf,
+        0xef81d3a28284402a,
+        0x110bdbf51c110a28,
+        0x9ae1b255d027e8f6,
+        0x7de3e0aa24688332,
+        0xe483c3ecd2067ee2,
+        0xf829328b276137e6,
+        0xa413ccad57562cad,
+        0xe6118e8b496acb1f,
+        0x8288dca6da5ec01f,
+        0xa53777dc88c17255,
+        0x8a00f1e0d5716eda,
+        0x618e6f47b7a720a8,
+        0x9e3907b0c692a841,
+        0x978b42ca963f34f3,
+        0x75e4b0cd98a7d7ef,
+        0xde4dbd6e0b5f4752,
+        0x252e4153f34493f,
+        0x50f0e7d803734ef9,
+        0x237766a38ed167ee,
+        0x4124414001ee39a0,
+        0xd08df643e535bb21,
+        0x34f575b5a9a80b74,
+        0x2c343af87297f755,
+        0xcd8b6d99d821f7cb,
+        0xe376fd7256fc48ae,
+        0xe1b06e7334352885,
+        0xfa87b26f86c169eb,
+        0x36c1604665a971de,
+        0xdba147c2239c8e80,
+        0x6b208e69fc7f0e24,
+        0x8795395b6f2b60c3,
+        0x5dabee9194907f4,
+        0xb98175142f5ed902,
+        0x5e1701e2021ddc81,
+        0x875aba2755eed08,
+        0x778d83289251de95,
+        0x3bfbe46a039ecb31,
+        0xb24704fce4cbd7f9,
+        0x6985ffe9a7c91e3d,
+        0xc8efb13df249dabb,
+        0xb1037e64b0f4c9f6,
+        0x55f69fd197d6b7c3,
+        0x672589d71d68a90c,
+        0xbebdb8224f50a77e,
+        0x3f589f80007374a7,
+        0xd307f4635954182a,
+        0xcff5850c10d4fd90,
+        0xc6da02dfb6408e15,
+        0x93daeef1e2b1a485,
+        0x65d833208aeea625,
+        0xe2b13fa13ed3b5fa,
+        0x67053538130fb68e,
+        0xc1042f6598218fa9,
+        0xee5badca749b8a2e,
+        0x6d22a3f947dae37d,
+        0xb62c6d1657f4dbaf,
+        0x6e007de69704c20b,
+        0x1af2b913fc3841d8,
+        0xdc0e47348e2e8e22,
+        0x9b1ddef1cf958b22,
+        0x632ed6b0233066b8,
+        0xddd02d3311bed8f2,
+        0xf147cfe1834656e9,
+        0x399aaa49d511597a,
+        0x6b14886979ec0309,
+        0x64fc4ac36b5afb97,
+        0xb82f78e07f7cf081,
+        0x10925c9a323d0e1b,
+        0xf451c79ee13c63f6,
+        0x7c2fc180317876c7,
+        0x35a12bd9eecb7d22,
+        0x335654a539621f90,
+        0xcc32a3f35db581f0,
+        0xc60748a80b2369cb,
+        0x7c4dd3b08591156b,
+        0xac1ced4b6de22291,
+        0xa32cfa2df134def5,
+        0x627108918dea2a53,
+        0x555b1608fcb4ff4,
+        0x143ee7ac43aaa33c,
+        0xdae90ce7cf4fc218,
+        0x4d68fc2582bcf4b5,
+        0x37094e1849135d71,
+        0xf7857e09f3d49fd8,
+        0x7538c503768be7,
+        0xedf648ba2f6be601,
+        0xaa347664dd72513e,
+        0xbe63893c6ef23b86,
+        0x130b85710605af97,
+        0xdd765c6b1ef6ab56,
+        0xf3249a629a97dc6b,
+        0x2a114f9020fab8e5,
+        0x5a69e027cfc6ad08,
+        0x3c4ccb36f1a5e050,
+        0x2e9e7d596834f0a5,
+        0x2430be6858fce789,
+        0xe90b862f2466e597,
+        0x895e2884f159a9ec,
+        0x26ab8fa4902fcb57,
+        0xa6efff5c54e1fa50,
+        0x333ac4e5811a8255,
+        0xa58d515f02498611,
+        0xfe5a09dcb25c6ef4,
+        0x3898988ab5f5818,
+        0x289ff6242af6c617,
+        0x3d9dd59fd381ea23,
+        0x52d7d93d8a8aae51,
+        0xc76a123d511f786f,
+        0xf68901edaf00c46c,
+        0x8c630871b590de80,
+        0x5209c308991e091,
+        0x1f809f99b4788177,
+        0x11170c2eb6c19fd8,
+        0x44433c779062ba58,
+        0xc0acb51af1874c45,
+        0x9f2e134284809fa1,
+        0xedb523bd15c619fa,
+        0x2d97fd53ecc23c0,
+        0xacaf05a34462374c,
+        0xddd9c6d34bffa11f,
+        0x1f81202d4d9d65d2,
+        0x105bec9aac08b22d,
+        0x96be6ede18b9d846,
+        0x74e67f10bded361a,
+        0xc93a15e6410a93dd,
+        0x40e4dad0741bb447,
+        0x3e5eb30aaec666c3,
+        0x831869bc2089b153,
+        0xc971e7b786263813,
+        0x6949e53e6de0e073,
+        0xb3e3f9729fdb8672,
+        0x3937f8a5dfa96a72,
+        0x98aff0bfc5ba5350,
+        0xc06f7b9d6ba0a901,
+        0x58b10a6c893cb7e3,
+        0x4e7042937e67c4a,
+        0x34a9732ad8edb8f4,
+        0xcfecf4d5475a64b2,
+        0xcd58f3fa4497b6f6,
+        0xf26993e0a228be4f,
+        0xfd2b6efd300b1d26,
+        0x9cc80887ab48f06e,
+        0x8b78e7e77cf2629a,
+        0xe929c6039488db27,
+        0xc87423a535ef3cc2,
+        0xde539e047f5a9e7c,
+        0xedd3eea92bd10d26,
+        0x4faf15bcfc68428e,
+        0x28f0383413f4629d,
+        0x3288476bdfed3500,
+        0xa54042aa09f72b65,
+        0xdf415997b05143fa,
+        0x527f543d0d4a24a0,
+        0x91699664ca1f5308,
+        0x919e5187a0c26eae,
+        0xd047ff64e8a37790,
+        0xbe07543799a76bf8,
+        0xa40673de569e40e8,
+        0x966555ce8d45b5f2,
+        0x5cd0ca6cf0b5b246,
+        0x79327c5a30b1ac3b,
+        0xc7e1f1e7c4be31c8,
+        0x55eadd1223fe5982,
+        0xa64a88f272bc924f,
+        0x69f4a9b1ff2015b0,
+        0x8db09b7d0eadd215,
+        0x6ccf5a42e356a5b0,
+        0x1cf1ae7ed8668ae8,
+        0x14848d1119ae3c4d,
+        0xa9caa3c007a3c948,
+        0xf595d8df34629d15,
+        0x42a7313250af1c4d,
+        0xb94da3eb3915c4c7,
+        0x3edc4a4542037f24,
+        0xda0142923cccc03f,
+        0x735dd66da588b7a6,
+        0x71149a419740b0e1,
+        0x31fb129af03cab9e,
+        0x8e779e6e3ff40e9f,
+        0x2e29532273032705,
+        0x4b6cad493492737a,
+        0xf6a46e430a357fc9,
+        0xe019d87d63696e0a,
+        0x8bffa5da44e2c1ca,
+        0xd07d1ac5fe9e9198,
+        0x7e8ed6f832a0bcf6,
+        0xbf462baf2821315d,
+        0xe258874d6386bc9,
+        0x8f858e756348ebc5,
+        0x4826a4df09fcf656,
+        0x953a82fc32206a62,
+        0x43609460941a56ce,
+        0x110ba574ab6f3a3d,
+        0xe93632e741e0b040,
+        0x2002a4371378eebb,
+        0xf45fac22dccabcf3,
+        0x6be3a6953c7c0cfe,
+        0xdf0ab692c13e1b72,
+        0x3234b3b2fa0021a8,
+        0x94040ae20d988689,
+        0x78f6c0a72b2bef62,
+        0x185bacce770b065d,
+        0x8b3e58a2496b7e0b,
+        0xc77e977ec08139ca,
+        0xc765f7b951be86c,
+        0x75df25e46edfc81c,
+        0x2f207dda6c190455,
+        0xbc57adeb6b7c2b9f,
+        0x6d3a9d5e8724c90c,
+        0x28f3331004e67532,
+        0x5558b9b8fe086363,
+        0xc94518b45004d377,
+        0x494baf01624d2c41,
+        0x6e9e326ecf82951e,
+        0x27d024b4cca252b4,
+        0x133663efe894a7ec,
+        0xb78ca4c9ec856c3c,
+        0xd2afaf34309bb7af,
+        0x14ce3bb107eb047b,
+        0x4a2fef83ba96b419,
+        0x4b3ac05cf306701c,
+        0xa97313b87214061f,
+        0xa4ad249a2fde7bfe,
+        0xb7e4d5d5ea87600f,
+        0x9621e3d20a7f9496,
+        0x8ae259bb52e32ff7,
+        0x85094cc13eef9052,
+        0xf4b120b50dc37c5f,
+        0xc985ff31bd1c14d1,
+        0x25feef222bd75dad,
+        0xe5e1a70b6f4d2a37,
+        0x95633bc739621e80,
+        0xf4d429931d0d9a6d,
+        0x16cf50e3759d2818,
+        0x1560f602b7883039,
+        0x1b72c0bcca35b1e1,
+        0x26deb16d3852af30,
+        0x4b111733b41a1c1e,
+        0xdbfd041235f0c6da,
+        0x9357f193e57377fb,
+        0xd8019102cec8a356,
+        0xe762a614e337571,
+        0x7c76cb2e6f93473c,
+        0x51e2ca911b4800ce,
+        0xcc804ebe13bf9b0a,
+        0xd0f7f9a3b5d74d7a,
+        0xe15b87324cacefe4,
+        0xa74a551fb88509cd,
+        0x6461de9d9141a9e2,
+        0xdd78b0cdc5f90e20,
+        0xa553186139c54eb5,
+        0xc5adf5ee28bf9178,
+        0xc877ef5957d9c1f6,
+        0xbd0a47cecfd1ce76,
+        0xcf32df0ffc774f3b,
+        0x26bdf8e3c7dafe50,
+        0x22fd0885aa7c0984,
+        0xa25247790bd53b6e,
+        0xdb91bedf4f0ae5ec,
+        0x98f85d45399d686f,
+        0xce0e3bf509499686,
+        0xb85c96a9b0918348,
+        0x49e8fe0db49ac505,
+        0x14ff20419aa9eae8,
+        0x75b8a9856b2cac42,
+        0x19d37193b71d7d72,
+        0xbe48e2722a814e76,
+        0xbaa77c8d1fb50b0c,
+        0x376beea800890735,
+        0xb08a057e4d8a7c2f,
+        0x7095b395e5649385,
+        0xb3643ea65973d044,
+        0xed9324bbc4f3adc1,
+        0xb869a8bc6bf42201,
+        0xea2426ad89b128d,
+        0xed2d32172727381a,
+        0x412fe4dc6700b5e7,
+        0x630339eb98f7a4,
+        0x1541d849bb20b36f,
+        0x5f511356ea341c87,
+        0x690fb442359ece0b,
+        0x3ef5dd6e97ff8179,
+        0x1e46395ee33381,
+        0x7a81a85a052bc97c,
+        0x7f2774e419c399bf,
+        0x430ad3117a9b54e0,
+        0x99e1e355c7674a0c,
+        0xb2fa8452c81cd64f,
+        0x9b09b5ab8639cc6b,
+        0xeec5afa0f66323da,
+        0x9567899a444e6f67,
+        0xf065090e80686e07,
+        0xe4cb17f9e856936e,
+        0x26ef7968db0225ec,
+        0x764852368ecb36e5,
+        0x33a0c3a7ff2ad8cb,
+        0x3f494ee1ecafd26d,
+        0xa15e51dd2eeb15af,
+        0x8903d1c42a430cd9,
+        0xefe620c847826b7f,
+        0x1a38afe48c543e0e,
+        0x750ac0389418b170,
+        0x76d2ec05fb6af527,
+        0xeeceedce6125a682,
+        0xfb74a77de0c10aac,
+        0x230762643aae6a9a,
+        0x28e8a859289d908b,
+        0xee1531968fd5478d,
+        0x7ae83d9d043f0c25,
+        0x1a98fcd6bcd6e761,
+        0xa81b75fc7ae97356,
+        0x6e71215a5a473b67,
+        0xe890f0ef5b16ae3,
+        0x1255fab2b60a8d8b,
+        0x1d68e93a13375c63,
+        0xab0836f272025bee,
+        0x8c600fbd1d568a3b,
+        0xa35c367cc5fe8c48,
+        0x4de965fa787bbce1,
+        0x2e85387e128079a4,
+        0x8f75f2fcf31efd3,
+        0x8e0c25988c52c639,
+        0x49c900dfa35bb388,
+        0xeea5425ec3f17194,
+        0x3385e99ec378749,
+        0xe0b802b11ae880e2,
+        0x88f61d8525e111b1,
+        0x686ea6673c254a06,
+        0x1db266e1461f45ad,
+        0x96c4f7bd7cc25e7a,
+        0x2b8b471b42b02abe,
+        0x4e8ed104a48ed7e1,
+        0xec7fc2c4cc6dcd,
+        0x786c26940f5e87aa,
+        0xc9d39b98ecf413d7,
+        0xc4851d12b0e27317,
+        0xe7bf2cb127687831,
+        0xe992f08658eb1ce1,
+        0x3e96c0a655c00157,
+        0xf083ebc7c7b2c134,
+        0x833c4ac06677e40f,
+        0xed7d8cd70e54b1df,
+        0xb7110bebb52323ba,
+        0xeed3eaf8887b3ef2,
+        0x4f26d91860286265,
+        0xf1267dfb38102602,
+        0x1963a2d9563e15ac,
+        0x522831438fa416e9,
+        0xe9650a38c98088cc,
+        0x4c90de305605dbf6,
+        0x1b438e70a00486bb,
+        0x233aa5faac9ae3b3,
+        0xfafba9007a751c96,
+        0x52f99e0591ba9071,
+        0xfe151ed73da248b9,
+        0x73b83aa4e126a2dc,
+        0x5fc84938fd177f86,
+        0xd62808df211f6300,
+        0xf3cfd85a0e537c75,
+        0xfb98361c03808e5,
+        0x28b5c22fadf32478,
+        0xca885d3d04702e67,
+        0x68ce8e3b56b9b1f,
+        0xb47850243b32aa07,
+        0x1f121a32c3134499,
+        0x35695e222a369583,
+        0x87ea7f79b408ef99,
+        0xb69aef44aa6928cb,
+        0x60d1b69f780acff3,
+        0xc32c1f92ddef6918,
+        0x4cdf7d7ed45ce9ea,
+        0xdda29c2fcd325f7,
+        0xa124ef0f0746343f,
+        0x3fbd6f006f9c2da4,
+        0x4ee9eecad37a150f,
+        0xa18581d91fb6f1a9,
+        0xc11f09c3bae10e8c,
+        0xb7490ac5c353908a,
+        0x1732c40accc477ef,
+        0x4d19f6b0aae1a0af,
+        0x5786a91688ed220c,
+        0xba1cb8987daa27bc,
+        0x546f0b382bb1e7be,
+        0x23a4ff0c7348bb1b,
+        0x11e8435bfdb4ddec,
+        0x8e87fecf0245e3a4,
+        0x3147e7a24dc5c05e,
+        0x802d101ae029199c,
+        0x59bc997377dd152e,
+        0x865147a770bd0f49,
+        0x2784f7e8b20e817d,
+        0x5acaf3a68a01a5ef,
+        0x3ccf03a654a039f5,
+        0x5b4a9ce43578a93c,
+        0x6b69830fcc245050,
+        0x45422850200e6d22,
+        0x7f52be4369b0b84e,
+        0x1afda67ff15e377b,
+        0x2c432bbd0d586d37,
+        0x4c0da3552cd77d33,
+        0xfc0674a59c64d8b8,
+        0x4e09587935a4fc92,
+        0x22a51c0576128082,
+        0x9e50f19d2f159953,
+        0x9fcdbc386f587b57,
+        0x7a418903dc84f7f,
+        0xcb676559f53b922,
+        0x1fbf1049db4fedea,
+        0x15eacafe0771cc8,
+        0x4ce6652171eb88f5,
+        0xa17a89f64c4d4083,
+        0x2f0f29ac33e7edb6,
+        0x723c48d08a113576,
+        0xf8e7a8fad9c31321,
+        0xb214e1235ba7dde0,
+        0xa1e36a0b95c97144,
+        0xa1f6b306b61c55a1,
+        0x57aa648e692570f1,
+        0xdfae1d828dd7e8d3,
+        0x4cbd82bea69f50ce,
+        0x8b1aa1cb813b4920,
+        0xfe5280acd4b4e511,
+        0xfb653eca71e85de9,
+        0x5d3de6385834eff5,
+        0xc3145480d55cca62,
+        0xd1c6decfe45067d6,
+        0xf0d5486860426435,
+        0x1ea63414a1a55cd9,
+        0x6b11bc65582fe8ec,
+        0x3cc3f297dcb89de8,
+        0x8429b8efc695546a,
+        0x6e5beaaccee7aedd,
+        0x34b634d4476f0f28,
+        0x331f6bdaa7cbbaff,
+        0x7de0543a45bdda9a,
+        0xa2c6382cee2aafb8,
+        0x38174caadc0e77d3,
+        0x75e6a47b508477b9,
+        0x6b58aad9d5c9d65,
+        0x6b70dbf37237255b,
+        0x822360e12bd44805,
+        0x36b728ff82c40fd5,
+        0xee3b2ef887e5d161,
+        0x2140d32e207a3671,
+        0x77ba2119b3ed18b5,
+        0x2e917a098a5a1684,
+        0x603b41454838db26,
+        0x7154a0cb14b20adb,
+        0xc04e308a32ae747c,
+        0x8d7876429305d59e,
+        0x3d9ebc8cf7704b42,
+        0xc8feefb029fc4474,
+        0x4f012b6adad4150,
+        0x6cc0ae19e8eb175b,
+        0x15d3d759aea2d597,
+        0x1acc520cb93f4c86,
+        0xf8838ae431238f20,
+        0xd1dfeb2e6a1ce8e8,
+        0xa14fe98befcd4554,
+        0xdb90a269bf013df2,
+        0x7b2a57ff3704efe1,
+        0xa9b71dc682fd76c7,
+        0x20b6d30ad539fd5f,
+        0x8a4dbf594b33640,
+        0x94b6ccd3205b4386,
+        0xb0a541a9a03d8e78,
+        0x3e337f1fcf40d9f2,
+        0x9ee41fb87deaa7f6,
+        0x132ba902475fb57b,
+        0xbd691c73aa5d4f5c,
+        0x63e9bb981f27226b,
+        0xdd0981b5eebae005,
+        0xf09311b629c0fabe,
+        0xecc87c4763b2c6e8,
+        0x785ba95a9b554e53,
+        0x6e05324c889b821d,
+        0x7a07f363ca344b1d,
+        0x90a179c4209c1757,
+        0xda995df8e2738ec3,
+        0x9dffc113b80074b1,
+        0x40e5e9c79894cb72,
+        0xfcce09f6769b40bf,
+        0xebd3a5dc9ff7a72e,
+        0x564ada87d22f115,
+        0x6db575a3b866505b,
+        0x19e1bfd869de77c4,
+        0x225cef7470876de7,
+        0x7146f8f7beeb57a2,
+        0x7ced457dfa369e80,
+        0xcf42b4a9a6059062,
+        0x658b0c108eedb0c1,
+        0xb765bf292ae455e5,
+        0x84430d6cae8c2e9c,
+        0xd4b5ba93059c747c,
+        0xd61211159b92b98d,
+        0x2a23d46249b5ce2c,
+        0x256f646c9385f2cb,
+        0x1f70970a7a61b3c7,
+        0x93bd7dc6bc9c579b,
+        0x967685d54c4f2187,
+        0xe480d122c29decca,
+        0x272f9e4db13f9c96,
+        0x5cba36fe22b9500,
+        0xf52b08c263dac737,
+        0xfc60b98ceb525394,
+        0x1c4ace3a7000ad62,
+        0x3b3cabf4a87e9563,
+        0x8591e9c9d2133180,
+        0xfb5014c26dbbecbb,
+        0x1875ab35becc06de,
+        0x956219542e09e6c2,
+        0x35e23bc080a756f0,
+        0x1fca0e9c74d5d48f,
+        0x4f491191e7afbaea,
+        0x316d915be08a5ae,
+        0xac55c806be8af56a,
+        0x1ed1ab45d2a3b90b,
+        0xa8f4553f29594177,
+        0x331b9dcce9d5e918,
+        0x907849919d55f0f5,
+        0x1b7419faaffccc4a,
+        0x335ed9ab8743fde9,
+        0xa1816b92ef46201,
+        0x40ff5762e6cb159f,
+        0x7154506770095dc8,
+        0xd07e3451331de46d,
+        0x36ed051194f1dea6,
+        0x96e05d674050a498,
+        0x1d8a8f5e361195fa,
+        0x8aa09b663104e4ba,
+        0x119d9780718495d0,
+        0xf9d590d24f490fa2,
+        0xe27e07ab7663ec32,
+        0xe577b339241a1889,
+        0x2d4920cdfb9b3f0f,
+        0x2c0dddc19103f934,
+        0x5d81f85168d1a3a9,
+        0x540290232a0d2bc9,
+        0xf300c2847237b546,
+        0xd0fd402eb061b0e7,
+        0x56de749888eba5cc,
+        0x9dad7bc4e4efd3c8,
+        0xf74aba6267bf7709,
+        0xe1f9a5d3c8ed764f,
+        0x5a2d2ba14475d118,
+        0x8a6d7a034881e143,
+        0xda4d69587cf6121c,
+        0xcf1a4b51451e9b1f,
+        0x8fbe72a081a9ece0,
+        0x32cf13f143d904f7,
+        0xd0ace449ef4b9cc5,
+        0x584c59aad40802d2,
+        0x99c00cf7323ecc48,
+        0xecd3da95e70e2fcf,
+        0x94849f1ebca77fa9,
+        0x91608a6c23ffb2f8,
+        0x7496cfd6de9fd0aa,
+        0x1f3cd7f5a9f1d840,
+        0x909ddef895fd4948,
+        0x3e6d894660f1e7e3,
+        0x2b65ce4307821b2a,
+        0xb7ddd39cc81a7dbd,
+        0x9dba8c33188dc0b6,
+        0x92e2dcd51fb1042e,
+        0xbc8e5992b252931,
+        0xcdb37918e79c82a7,
+        0xe6ad7a960a900d00,
+        0x73e366b83f726576,
+        0x977219877aadb582,
+        0xac0e9e21c6c53396,
+        0x5d50ad978ae0d6b9,
+        0x33e79f078c712195,
+        0xb780b4a62ea4a88c,
+        0x46880e10c6fe5e9f,
+        0xf121b50c83cd6de8,
+        0x87f2ef33a2c74f28,
+        0x53237a77cbd5a390,
+        0x38512a413ad82ccb,
+        0xc6b6b658ec00115f,
+        0x9b0195d9075e275e,
+        0x2a288411896a2fd4,
+        0xe21c167bf9134f48,
+        0x484342d1e5311e9e,
+        0x6b0271fe2687ae8d,
+        0xe9936f77dbadcd62,
+        0x3fc2bb1409152a54,
+        0x544b1ebc8c707ac6,
+        0xcb2c7f35532857fd,
+        0xe2ec0ccb839700aa,
+        0x9809869ca5d3f8b8,
+        0x4ac7e4c49fb11125,
+        0x9dc1a89ad12e4015,
+        0x821363070b9f1872,
+        0x792278da649773ed,
+        0x207f7374bf7f8c1b,
+        0x701310a8e3eae06f,
+        0x48aa96504a98650d,
+        0x2e9dc73f48487535,
+        0x792fb1d39ed9f291,
+        0xdd2aa15450a5d688,
+        0xf277dac4e230f740,
+        0x4dc630075f0d6966,
+        0x13271a9e3760a771,
+        0x31d7462a9af7a821,
+        0x4cc716998e6fd25d,
+        0x954230f5007579a2,
+        0x895da71f627eff70,
+        0x85d46ceffa4de5ce,
+        0x394b39770d8bfb16,
+        0xbab565f9f04a8f68,
+        0x6068ffdd1a7981f3,
+        0x73b236e2438afcee,
+        0x8811d89f40f1bc1e,
+        0xf8e6927e61ea0d51,
+        0xb50b067f8bae7042,
+        0xf579493998c0146d,
+        0x1a2722c1f38056cf,
+        0xfd1d9ff7584f3d94,
+        0x397a4ecc64a515dd,
+        0x61ae3aa5907c9292,
+        0x711c779635f63690,
+        0x55052d4088c3e8d2,
+        0xd8addc8035811e69,
+        0x3ca47eac346b71b,
+        0xeeab256c43095b9,
+        0x2c3962254558aac,
+        0xb20717d6298aa067,
+        0xa925a6577a0d7fa2,
+        0xc7defd217c609f06,
+        0x12bd765a01c17713,
+        0xb84f423c0ba9ac9,
+        0x97bc352dd450db11,
+        0xc5489c2f221bd4ae,
+        0x1bc28d6a021d832,
+        0x34ee8e72f89c28f8,
+        0x76de4f7f7febf179,
+        0xa625c3787f272ede,
+        0x7e04174ecb2c3f2f,
+        0x779ff390ef540623,
+        0x16cad5dba4e260aa,
+        0x461963215ed01bfe,
+        0xa344ed9350cbcf87,
+        0x8d2e141ddec4189a,
+        0xb60056d947b1e61,
+        0x206da2075a745660,
+        0xa6c86a8cc1d4aae6,
+        0x14d9eaf9a51764e7,
+        0xbdcf406c17fe9a24,
+        0x1fe9befd266a7fb4,
+        0xafa84033d88fd517,
+        0xd3ab07b968a72a59,
+        0xfe62f54a778d181c,
+        0x276e8acc623ce437,
+        0xb8b724523a5e3ff9,
+        0xc26cb4c5f9ae036d,
+        0x92bd12085bd4a96a,
+        0x393c89fd25c394db,
+        0xc78ee8904ed57606,
+        0xa00f1597c7c2695b,
+        0xc498f517be87481b,
+        0xb7f4e26408afbf4b,
+        0xc7bd3dac4cac656,
+        0xd1f98ac5b7b83c4a,
+        0xfbb66d5587a6dd0d,
+        0x2e5d02b5c396651c,
+        0x268edffba04c1616,
+        0xb3061f97feb0ce11,
+        0x7c25d4087fceee6b,
+        0xf507137a49a87fe6,
+        0x9da9477b7a562e8,
+        0xbfaa8ec4810cb08c,
+        0x41cddaeec29a96e8,
+        0x3818ab84c2197b62,
+        0x56357b4b5c1266bd,
+        0xafb1279f0aaaa237,
+        0x5d2045ac38d97de7,
+        0x1b3afe07cb91e27d,
+        0xf4c4eeca3220eb13,
+        0xa92833910e789ce7,
+        0xb5efa75f584e9e8d,
+        0x898bd515b59ba1e5,
+        0x29909fd11d8cd3c5,
+        0xc3af9ce1f11d2789,
+        0x5e0975542c552e,
+        0x7d3bba3bc91dbdf8,
+        0xdeeabf7c3dc280b1,
+        0x11b3b68d228755c0,
+        0xde6e5c68a70bed87,
+        0x7dd40cbc4c59802c,
+        0xc9187d78a6b0d218,
+        0xe62ad80f892ee6e0,
+        0x42c8b52d9a77438f,
+        0x846e53c0fb404810,
+        0x8c474f5c18c9349,
+        0xb7d24891a2890d32,
+        0x6cddd68d4a5ea817,
+        0x25d3d8966d7ecbb,
+        0xeb64c4703c8abba,
+        0x9212ede8ecbac8a3,
+        0x7d8623e01edbd3eb,
+        0x58f7d8a25f951332,
+        0x426ad304534eacc1,
+        0x39dbf2090805098d,
+        0xb17aa1930baf63fa,
+        0x2b4993ba85684ae5,
+        0xf9d81bfc5df35e52,
+        0xf1f56be6cf7bc7f5,
+        0x3575ad8158c70911,
+        0xc7309121c2ecac0d,
+        0x4ec1234ce389645c,
+        0x93a1cf9efb471f73,
+        0x3701444789ac5957,
+        0xa00c480b1fef82a1,
+        0x121b10322274ca2b,
+        0x6386fa30b4859069,
+        0xf634590ca87ae9fa,
+        0x67caa825bc4d3eef,
+        0x7abb9f1f55b67bce,
+        0xdd8b4babed54b018,
+        0x78d20e7c416df3eb,
+        0x912059fae7719d63,
+        0x52c7a5da9931c062,
+        0x6b716823ccc02930,
+        0x48d61f54e3b375d4,
+        0xf21323eeeecc4dd1,
+        0x11eb883f574ad03b,
+        0x1e8a352888c32d29,
+        0xe75adc205c7f52bb,
+        0x32c8d6c2a4539cda,
+        0x73e65eae7911cfe1,
+        0x87547e85703238c1,
+        0xd65ed50b763806a,
+        0xd5473bce681b902a,
+        0x5f167e50304e2a97,
+        0x167f19290945366e,
+        0x5ef96d0ec88b2cd6,
+        0xe6480b7bff0df2e8,
+        0x45317ae8cacfd972,
+        0x65260ecc3407f8a,
+        0xe375751fba51420d,
+        0x6be1bb751561dfed,
+        0xa84bdb92c80f45ce,
+        0xe4638840049ff341,
+        0xd7c1b89403764784,
+        0x15200856f43d2a5e,
+        0xe28c8cb401861d4f,
+        0x56bc2c05f71f3da6,
+        0x5c04d9140c31703a,
+    ];
+}
@@ -0,0 +1,192 @@
+use crate::error::RandError;
+use crate::fork;
+use crate::reader;
+use crate::source::Source;
+
+const BUF_LEN: usize = 256;
+
+/// A CryptoSafe source produces output that is unpredictable even to
+/// someone who has seen earlier output, so it may be used for tokens
+/// and keys.
+///
+/// It is a marker trait: functions that must not run on a seedable
+/// generator can require it at compile time.
+///
+/// # Examples
+///
+/// ```no_run
+/// use srand::{CryptoSafe, CryptoSource, Rand};
+///
+/// fn token<S: CryptoSafe>(r: &mut Rand<S>) -> String {
+///     (0..16).map(|_| format!("{:02x}", r.uint32n(256))).collect()
+/// }
+///
+/// fn main() -> std::io::Result<()> {
+///     let mut r: Rand<_> = Rand::new(CryptoSource::new()?);
+///     println!("token: {}", token(&mut r));
+///     Ok(())
+/// }
+/// ```
+pub trait CryptoSafe: Source {}
+
+/// A CryptoSource is a Source whose values are read from the operating
+/// system's cryptographically secure generator, see `srand::read`.
+///
+/// Reads go through an internal buffer, so most calls do not enter the
+/// kernel. Since the source cannot be seeded, `seed` does nothing.
+///
+/// Buffered bytes are discarded when the process forks, so a parent
+/// and its child never hand out the same values.
+///
+/// The Source methods cannot return errors, so they panic if the
+/// system fails to provide randomness after the source was created.
+pub struct CryptoSource {
+    buf: [u8; BUF_LEN],
+    pos: usize,
+    fork: u64,
+}
+
+impl CryptoSource {
+    /// Creates a new CryptoSource, filling its buffer so that an
+    /// unavailable system generator is reported here.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{CryptoSource, Rand};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut r: Rand<_> = Rand::new(CryptoSource::new()?);
+    ///     println!("n: {}", r.uint64());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new() -> Result<CryptoSource, RandError> {
+        let mut src = CryptoSource {
+            buf: [0; BUF_LEN],
+            pos: BUF_LEN,
+            fork: fork::generation(),
+        };
+        src.refill()?;
+        Ok(src)
+    }
+
+    fn refill(&mut self) -> Result<(), RandError> {
+        reader::read(&mut self.buf)?;
+        self.pos = 0;
+        Ok(())
+    }
+
+    /// Discards the buffer if the process has forked since it was
+    /// filled, since the other process holds the same bytes.
+    fn check_fork(&mut self) {
+        let generation = fork::generation();
+        if self.fork != generation {
+            self.buf.fill(0);
+            self.pos = BUF_LEN;
+            self.fork = generation;
+        }
+    }
+
+    fn next_bytes<const N: usize>(&mut self) -> [u8; N] {
+        self.check_fork();
+        if self.pos + N > BUF_LEN {
+            self.refill()
+                .expect("srand: failed to read system randomness");
+        }
+        let mut b = [0u8; N];
+        b.copy_from_slice(&self.buf[self.pos..self.pos + N]);
+        // Bytes are never handed out twice.
+        self.buf[self.pos..self.pos + N].fill(0);
+        self.pos += N;
+        b
+    }
+}
+
+impl Source for CryptoSource {
+    /// Does nothing: the values of a CryptoSource cannot be
+    /// reproduced from a seed.
+    fn seed(&mut self, _seed: i64) {}
+
+    fn uint32(&mut self) -> u32 {
+        u32::from_le_bytes(self.next_bytes())
+    }
+
+    fn uint64(&mut self) -> u64 {
+        u64::from_le_bytes(self.next_bytes())
+    }
+
+    fn int64(&mut self) -> i64 {
+        (self.uint64() & !(1 << 63)) as i64
+    }
+
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        self.check_fork();
+        let mut filled = 0;
+        while filled < buf.len() {
+            if self.pos == BUF_LEN {
+                self.refill()
+                    .expect("srand: failed to read system randomness");
+            }
+            let n = (BUF_LEN - self.pos).min(buf.len() - filled);
+            buf[filled..filled + n].copy_from_slice(&self.buf[self.pos..self.pos + n]);
+            self.buf[self.pos..self.pos + n].fill(0);
+            self.pos += n;
+            filled += n;
+        }
+    }
+}
+
+impl CryptoSafe for CryptoSource {}
+
+#[cfg(test)]
+mod test {
+    use super::{CryptoSafe, CryptoSource};
+    use crate::rand::Rand;
+    use crate::source::Source;
+
+    fn secure<S: CryptoSafe>(r: &mut Rand<S>) -> u64 {
+        r.uint64()
+    }
+
+    #[test]
+    fn examples() {
+        let mut r: Rand<_> = Rand::new(CryptoSource::new().unwrap());
+        let mut get = vec![];
+        // Several buffers' worth, mixing 32- and 64-bit reads.
+        for _i in 0..100 {
+            get.push(r.uint32() as u64);
+            get.push(secure(&mut r));
+        }
+        get.sort_unstable();
+        get.dedup();
+        assert_eq!(200, get.len());
+
+        let mut src = CryptoSource::new().unwrap();
+        let a = src.uint64();
+        src.seed(1);
+        let b = src.uint64();
+        src.seed(1);
+        assert_ne!(a, b);
+        assert_ne!(b, src.uint64());
+        assert!(r.int64() >= 0);
+        assert!((0..1000).all(|_| r.float64() < 1.0));
+
+        // Spans several refills of the internal buffer.
+        let mut buf = vec![0u8; 1000];
+        r.fill_bytes(&mut buf);
+        assert!(buf.chunks(8).all(|c| c.iter().any(|&b| b != 0)));
+    }
+
+    #[test]
+    fn fork_discards_buffer() {
+        let mut src = CryptoSource::new().unwrap();
+        src.uint64();
+        let next = src.buf[src.pos..src.pos + 8].to_vec();
+        // A changed generation looks like a fork.
+        src.fork = src.fork.wrapping_add(1);
+        assert_ne!(next, src.uint64().to_le_bytes());
+        assert_eq!(8, src.pos);
+        assert_eq!(crate::fork::generation(), src.fork);
+    }
+}
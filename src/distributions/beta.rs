@@ -0,0 +1,64 @@
+use crate::distributions::gamma::ln_standard_gamma;
+use crate::distributions::{positive, Distribution};
+use crate::error::RandError;
+use crate::rand::Rand;
+use crate::source::Source;
+
+/// A Beta is the beta distribution on [0, 1] with shape parameters α
+/// and β, whose density is proportional to x^(α-1) (1-x)^(β-1).
+#[derive(Clone, Copy, Debug)]
+pub struct Beta {
+    alpha: f64,
+    beta: f64,
+}
+
+impl Beta {
+    /// Creates a new Beta distribution.
+    /// Requirements: alpha > 0 and beta > 0, otherwise
+    /// `RandError::InvalidArgument` is returned.
+    pub fn new(alpha: f64, beta: f64) -> Result<Beta, RandError> {
+        if !positive(alpha) || !positive(beta) {
+            return Err(RandError::InvalidArgument(
+                "beta: requires alpha > 0 and beta > 0",
+            ));
+        }
+        Ok(Beta { alpha, beta })
+    }
+}
+
+impl Distribution<f64> for Beta {
+    fn sample<S: Source>(&self, r: &mut Rand<S>) -> f64 {
+        // X / (X + Y) for X ~ Gamma(α) and Y ~ Gamma(β), computed from
+        // ln X and ln Y since tiny shapes make X and Y underflow to 0.
+        let lx = ln_standard_gamma(r, self.alpha);
+        let ly = ln_standard_gamma(r, self.beta);
+        let m = lx.max(ly);
+        let ln_sum = m + ((lx - m).exp() + (ly - m).exp()).ln();
+        (lx - ln_sum).exp()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Beta;
+    use crate::distributions::fit::{beta_i, ks};
+    use crate::rand::Rand;
+    use crate::source::RngSource;
+
+    #[test]
+    fn beta() {
+        for &(a, b) in &[(0.5, 0.5), (1.0, 3.0), (2.0, 5.0), (20.0, 0.7)] {
+            let dist = Beta::new(a, b).unwrap();
+            ks(3, dist, |x| beta_i(a, b, x));
+        }
+        assert!(Beta::new(1.0, 0.0).is_err());
+
+        // Both gamma variates underflow to 0 for shapes this small, and
+        // the mass sits next to 0 and 1.
+        let mut r: Rand<_> = Rand::new(RngSource::new(1));
+        let tiny = Beta::new(1e-20, 1e-20).unwrap();
+        let xs: Vec<f64> = r.sample_iter(tiny).take(1000).collect();
+        assert!(xs.iter().all(|x| (0.0..=1.0).contains(x)));
+        assert!(xs.iter().any(|&x| x < 0.5) && xs.iter().any(|&x| x > 0.5));
+    }
+}
@@ -0,0 +1,242 @@
+use crate::distributions::Distribution;
+use crate::error::RandError;
+use crate::rand::Rand;
+use crate::source::Source;
+
+/// A Binomial is the number of successes in n independent trials that
+/// each succeed with probability p.
+///
+/// When n·min(p, 1-p) < 30 it is drawn by inversion, and otherwise by
+/// the BTPE algorithm, whose cost does not grow with n.
+///
+/// See "Binomial random variate generation"
+/// (Kachitvichyanukul & Schmeiser, 1988)
+/// https://doi.org/10.1145/42372.42381
+#[derive(Clone, Copy, Debug)]
+pub struct Binomial {
+    n: u64,
+    p: f64,
+}
+
+impl Binomial {
+    /// Creates a new Binomial distribution.
+    /// Requirements: 0 <= p <= 1, otherwise `RandError::InvalidArgument`
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Binomial, Rand, RngSource};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     let defects = Binomial::new(10000, 0.002)?;
+    ///     println!("n: {}", r.sample(&defects));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(n: u64, p: f64) -> Result<Binomial, RandError> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(RandError::InvalidArgument("binomial: requires 0 <= p <= 1"));
+        }
+        Ok(Binomial { n, p })
+    }
+}
+
+impl Distribution<u64> for Binomial {
+    fn sample<S: Source>(&self, r: &mut Rand<S>) -> u64 {
+        // Both methods want p <= 1/2; count failures instead otherwise.
+        let p = self.p.min(1.0 - self.p);
+        let k = if p == 0.0 {
+            0
+        } else if self.n as f64 * p < 30.0 {
+            inversion(r, self.n, p)
+        } else {
+            btpe(r, self.n, p)
+        };
+        if self.p > 0.5 {
+            self.n - k
+        } else {
+            k
+        }
+    }
+}
+
+/// Returns a binomial variate by searching the CDF from 0, for small
+/// n·p and p <= 1/2.
+fn inversion<S: Source>(r: &mut Rand<S>, n: u64, p: f64) -> u64 {
+    let q = 1.0 - p;
+    // ln_1p keeps q^n accurate when p is too small to change 1 - p.
+    let qn = (n as f64 * (-p).ln_1p()).exp();
+    let np = n as f64 * p;
+    let bound = (n as f64).min(np + 10.0 * (np * q + 1.0).sqrt());
+    let mut x = 0;
+    let mut px = qn;
+    let mut u = r.float64();
+    while u > px {
+        x += 1;
+        if x as f64 > bound {
+            // Rounding pushed u past the mass that matters; start over.
+            x = 0;
+            px = qn;
+            u = r.float64();
+        } else {
+            u -= px;
+            px = ((n - x + 1) as f64 * p * px) / (x as f64 * q);
+        }
+    }
+    x
+}
+
+/// Returns a binomial variate by the BTPE (Binomial, Triangle,
+/// Parallelogram, Exponential) algorithm, for n·p >= 30 and p <= 1/2.
+fn btpe<S: Source>(rand: &mut Rand<S>, n: u64, p: f64) -> u64 {
+    let nf = n as f64;
+    let r = p;
+    let q = 1.0 - r;
+    let fm = nf * r + r;
+    let m = fm.floor();
+    let p1 = (2.195 * (nf * r * q).sqrt() - 4.6 * q).floor() + 0.5;
+    let xm = m + 0.5;
+    let xl = xm - p1;
+    let xr = xm + p1;
+    let c = 0.134 + 20.5 / (15.3 + m);
+    let a = (fm - xl) / (fm - xl * r);
+    let laml = a * (1.0 + a / 2.0);
+    let a = (xr - fm) / (xr * q);
+    let lamr = a * (1.0 + a / 2.0);
+    let p2 = p1 * (1.0 + 2.0 * c);
+    let p3 = p2 + c / laml;
+    let p4 = p3 + c / lamr;
+    let nrq = nf * r * q;
+
+    loop {
+        let u = rand.float64() * p4;
+        let mut v = rand.float64();
+        let y;
+        if u <= p1 {
+            // Triangular region: accept at once.
+            return (xm - p1 * v + u).floor() as u64;
+        } else if u <= p2 {
+            // Parallelogram region.
+            let x = xl + (u - p1) / c;
+            v = v * c + 1.0 - (m - x + 0.5).abs() / p1;
+            if v > 1.0 {
+                continue;
+            }
+            y = x.floor();
+        } else if u <= p3 {
+            // Left exponential tail.
+            y = (xl + v.ln() / laml).floor();
+            if y < 0.0 || v == 0.0 {
+                continue;
+            }
+            v *= (u - p2) * laml;
+        } else {
+            // Right exponential tail.
+            y = (xr - v.ln() / lamr).floor();
+            if y > nf || v == 0.0 {
+                continue;
+            }
+            v *= (u - p3) * lamr;
+        }
+
+        let k = (y - m).abs();
+        if k <= 20.0 || k >= nrq / 2.0 - 1.0 {
+            // Evaluate f(y)/f(m) by recursion.
+            let s = r / q;
+            let a = s * (nf + 1.0);
+            let mut f = 1.0;
+            if m < y {
+                let mut i = m + 1.0;
+                while i <= y {
+                    f *= a / i - s;
+                    i += 1.0;
+                }
+            } else if m > y {
+                let mut i = y + 1.0;
+                while i <= m {
+                    f /= a / i - s;
+                    i += 1.0;
+                }
+            }
+            if v <= f {
+                return y as u64;
+            }
+            continue;
+        }
+
+        // Squeeze using upper and lower bounds on log(f(y)/f(m)).
+        let rho = (k / nrq) * ((k * (k / 3.0 + 0.625) + 0.16666666666666666) / nrq + 0.5);
+        let t = -k * k / (2.0 * nrq);
+        let alv = v.ln();
+        if alv < t - rho {
+            return y as u64;
+        }
+        if alv > t + rho {
+            continue;
+        }
+
+        // Final comparison with Stirling's formula.
+        let x1 = y + 1.0;
+        let f1 = m + 1.0;
+        let z = nf + 1.0 - m;
+        let w = nf - y + 1.0;
+        let stirling = |x: f64| {
+            let x2 = x * x;
+            (13680.0 - (462.0 - (132.0 - (99.0 - 140.0 / x2) / x2) / x2) / x2) / x / 166320.0
+        };
+        let bound = xm * (f1 / x1).ln()
+            + (nf - m + 0.5) * (z / w).ln()
+            + (y - m) * (w * r / (x1 * q)).ln()
+            + stirling(f1)
+            + stirling(z)
+            + stirling(x1)
+            + stirling(w);
+        if alv <= bound {
+            return y as u64;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Binomial;
+    use crate::distributions::fit::{chi_square, ln_choose};
+    use crate::rand::Rand;
+    use crate::source::RngSource;
+
+    #[test]
+    fn binomial() {
+        let cases: Vec<(u64, f64)> = vec![
+            (20, 0.3),
+            (100, 0.05),
+            (50, 0.9),
+            (1000, 0.4),
+            (1000, 0.7),
+            (1000000, 0.25),
+        ];
+        for (n, p) in cases {
+            let dist = Binomial::new(n, p).unwrap();
+            chi_square(3, dist, |k| {
+                if k > n {
+                    return 0.0;
+                }
+                (ln_choose(n, k) + k as f64 * p.ln() + (n - k) as f64 * (1.0 - p).ln()).exp()
+            });
+        }
+
+        let mut r: Rand<_> = Rand::new(RngSource::new(1));
+        assert_eq!(0, r.sample(&Binomial::new(10, 0.0).unwrap()));
+        assert_eq!(10, r.sample(&Binomial::new(10, 1.0).unwrap()));
+        assert_eq!(0, r.sample(&Binomial::new(0, 0.5).unwrap()));
+        assert!(Binomial::new(10, 1.5).is_err());
+        assert!(Binomial::new(10, f64::NAN).is_err());
+
+        // 1 - p rounds to 1 here, so q^n must come from ln_1p.
+        let dist = Binomial::new(u64::MAX, 1e-18).unwrap();
+        let sum: u64 = (0..10000).map(|_| r.sample(&dist)).sum();
+        let mean = u64::MAX as f64 * 1e-18;
+        assert!((sum as f64 / 10000.0 - mean).abs() < 0.3);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::distributions::{positive, Distribution};
+use crate::error::RandError;
+use crate::rand::Rand;
+use crate::source::Source;
+use std::f64::consts::PI;
+
+/// A Cauchy is the Cauchy distribution with location x0 and scale γ.
+/// It has no mean or variance, which makes it a useful model of
+/// heavy-tailed noise.
+#[derive(Clone, Copy, Debug)]
+pub struct Cauchy {
+    location: f64,
+    scale: f64,
+}
+
+impl Cauchy {
+    /// Creates a new Cauchy distribution.
+    /// Requirements: location is finite and scale > 0, otherwise
+    /// `RandError::InvalidArgument` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Cauchy, Rand, RngSource};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     let d = Cauchy::new(0.0, 1.0)?;
+    ///     println!("n: {}", r.sample(&d));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(location: f64, scale: f64) -> Result<Cauchy, RandError> {
+        if !location.is_finite() || !positive(scale) {
+            return Err(RandError::InvalidArgument(
+                "cauchy: requires finite location and scale > 0",
+            ));
+        }
+        Ok(Cauchy { location, scale })
+    }
+}
+
+impl Distribution<f64> for Cauchy {
+    fn sample<S: Source>(&self, r: &mut Rand<S>) -> f64 {
+        // Inverse of the CDF, 1/2 + atan((x-x0)/γ)/π.
+        self.location + self.scale * (PI * (r.float64() - 0.5)).tan()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Cauchy;
+    use crate::distributions::fit::ks;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn cauchy() {
+        for &(x0, gamma) in &[(0.0, 1.0), (3.0, 0.5), (-10.0, 20.0)] {
+            let dist = Cauchy::new(x0, gamma).unwrap();
+            ks(6, dist, |x: f64| 0.5 + ((x - x0) / gamma).atan() / PI);
+        }
+        assert!(Cauchy::new(0.0, 0.0).is_err());
+        assert!(Cauchy::new(f64::INFINITY, 1.0).is_err());
+    }
+}
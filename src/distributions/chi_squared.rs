@@ -0,0 +1,46 @@
+use crate::distributions::gamma::standard_gamma;
+use crate::distributions::{positive, Distribution};
+use crate::error::RandError;
+use crate::rand::Rand;
+use crate::source::Source;
+
+/// A ChiSquared is the chi-squared distribution with k degrees of
+/// freedom, the distribution of a sum of k squared standard normals.
+#[derive(Clone, Copy, Debug)]
+pub struct ChiSquared {
+    k: f64,
+}
+
+impl ChiSquared {
+    /// Creates a new ChiSquared distribution with k degrees of freedom.
+    /// Requirements: k > 0, otherwise `RandError::InvalidArgument` is
+    /// returned.
+    pub fn new(k: f64) -> Result<ChiSquared, RandError> {
+        if !positive(k) {
+            return Err(RandError::InvalidArgument("chi squared: requires k > 0"));
+        }
+        Ok(ChiSquared { k })
+    }
+}
+
+impl Distribution<f64> for ChiSquared {
+    fn sample<S: Source>(&self, r: &mut Rand<S>) -> f64 {
+        // ChiSquared(k) = Gamma(k/2, 2)
+        standard_gamma(r, 0.5 * self.k) * 2.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ChiSquared;
+    use crate::distributions::fit::{gamma_p, ks};
+
+    #[test]
+    fn chi_squared() {
+        for &k in &[0.5, 1.0, 3.0, 30.0] {
+            let dist = ChiSquared::new(k).unwrap();
+            ks(2, dist, |x| gamma_p(0.5 * k, 0.5 * x));
+        }
+        assert!(ChiSquared::new(0.0).is_err());
+    }
+}
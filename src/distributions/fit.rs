@@ -0,0 +1,192 @@
+//! Goodness-of-fit testing of distributions: Kolmogorov-Smirnov for
+//! continuous ones against their CDFs, chi-square for discrete ones
+//! against their probability mass functions, and the special functions
+//! those need.
+
+use crate::distributions::gamma::ln_gamma;
+use crate::distributions::Distribution;
+use crate::rand::Rand;
+use crate::source::RngSource;
+
+const SAMPLES: usize = 10000;
+
+/// Draws SAMPLES values from dist at seed and panics if the
+/// Kolmogorov-Smirnov statistic against cdf exceeds the critical value
+/// at the 0.1% significance level.
+pub(crate) fn ks<D: Distribution<f64>>(seed: i64, dist: D, cdf: impl Fn(f64) -> f64) {
+    let mut r: Rand<_> = Rand::new(RngSource::new(seed));
+    let mut v: Vec<f64> = r.sample_iter(dist).take(SAMPLES).collect();
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = SAMPLES as f64;
+    let mut d: f64 = 0.0;
+    for (i, &x) in v.iter().enumerate() {
+        let f = cdf(x);
+        d = d.max(f - i as f64 / n).max((i + 1) as f64 / n - f);
+    }
+    let critical = 1.949 / n.sqrt();
+    assert!(d < critical, "KS statistic {} >= {}", d, critical);
+}
+
+/// Draws SAMPLES values from dist at seed and panics if a chi-square
+/// test against pmf rejects them at the 0.1% significance level.
+/// Neighbouring values are pooled until every bin expects at least 5
+/// samples.
+pub(crate) fn chi_square<D: Distribution<u64>>(seed: i64, dist: D, pmf: impl Fn(u64) -> f64) {
+    let mut r: Rand<_> = Rand::new(RngSource::new(seed));
+    let v: Vec<u64> = r.sample_iter(dist).take(SAMPLES).collect();
+    let max = *v.iter().max().unwrap();
+    let mut counts = vec![0u64; max as usize + 1];
+    for &x in &v {
+        counts[x as usize] += 1;
+    }
+    let n = SAMPLES as f64;
+    let mut bins: Vec<(f64, f64)> = vec![];
+    let (mut observed, mut expected, mut cum) = (0.0, 0.0, 0.0);
+    for (k, &count) in counts.iter().enumerate() {
+        let p = pmf(k as u64);
+        cum += p;
+        observed += count as f64;
+        expected += n * p;
+        if expected >= 5.0 {
+            bins.push((observed, expected));
+            observed = 0.0;
+            expected = 0.0;
+        }
+    }
+    // The upper tail was never observed; pool it with the last bin.
+    expected += n * (1.0 - cum).max(0.0);
+    match bins.last_mut() {
+        Some(last) => *last = (last.0 + observed, last.1 + expected),
+        None => bins.push((observed, expected)),
+    }
+    let stat: f64 = bins.iter().map(|&(o, e)| (o - e) * (o - e) / e).sum();
+    let df = (bins.len() - 1) as f64;
+    let p = 1.0 - gamma_p(0.5 * df, 0.5 * stat);
+    assert!(
+        p > 0.001,
+        "chi-square {} with {} bins, p = {}",
+        stat,
+        bins.len(),
+        p
+    );
+}
+
+/// Returns the regularized lower incomplete gamma function P(a, x).
+///
+/// See Numerical Recipes, section 6.2.
+pub(crate) fn gamma_p(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    let front = (-x + a * x.ln() - ln_gamma(a)).exp();
+    if x < a + 1.0 {
+        // Series representation.
+        let (mut ap, mut del) = (a, 1.0 / a);
+        let mut sum = del;
+        for _i in 0..1000 {
+            ap += 1.0;
+            del *= x / ap;
+            sum += del;
+            if del.abs() < sum.abs() * EPS {
+                break;
+            }
+        }
+        return sum * front;
+    }
+    // Continued fraction for Q(a, x), by the modified Lentz method.
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / TINY;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..1000 {
+        let an = -(i as f64) * (i as f64 - a);
+        b += 2.0;
+        d = nonzero(an * d + b);
+        c = nonzero(b + an / c);
+        d = 1.0 / d;
+        h *= d * c;
+        if (d * c - 1.0).abs() < EPS {
+            break;
+        }
+    }
+    1.0 - front * h
+}
+
+/// Returns the regularized incomplete beta function I_x(a, b).
+///
+/// See Numerical Recipes, section 6.4.
+pub(crate) fn beta_i(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    if x > (a + 1.0) / (a + b + 2.0) {
+        return 1.0 - beta_i(b, a, 1.0 - x);
+    }
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    // Continued fraction, by the modified Lentz method.
+    let mut c = 1.0;
+    let mut d = 1.0 / nonzero(1.0 - (a + b) * x / (a + 1.0));
+    let mut h = d;
+    for m in 1..1000 {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+        let aa = m * (b - m) * x / ((a - 1.0 + m2) * (a + m2));
+        d = 1.0 / nonzero(1.0 + aa * d);
+        c = nonzero(1.0 + aa / c);
+        h *= d * c;
+        let aa = -(a + m) * (a + b + m) * x / ((a + m2) * (a + 1.0 + m2));
+        d = 1.0 / nonzero(1.0 + aa * d);
+        c = nonzero(1.0 + aa / c);
+        h *= d * c;
+        if (d * c - 1.0).abs() < EPS {
+            break;
+        }
+    }
+    front * h / a
+}
+
+/// Returns the error function erf(x).
+pub(crate) fn erf(x: f64) -> f64 {
+    gamma_p(0.5, x * x).copysign(x)
+}
+
+const EPS: f64 = 1e-15;
+const TINY: f64 = 1e-300;
+
+fn nonzero(x: f64) -> f64 {
+    if x.abs() < TINY {
+        TINY
+    } else {
+        x
+    }
+}
+
+/// Returns ln(n choose k) for k <= n.
+pub(crate) fn ln_choose(n: u64, k: u64) -> f64 {
+    ln_gamma(n as f64 + 1.0) - ln_gamma(k as f64 + 1.0) - ln_gamma((n - k) as f64 + 1.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{beta_i, erf, gamma_p};
+    use crate::distributions::gamma::ln_gamma;
+
+    #[test]
+    fn special_functions() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+        assert!(close(ln_gamma(10.0), 362880f64.ln()));
+        assert!(close(ln_gamma(0.5), std::f64::consts::PI.sqrt().ln()));
+        // P(1, x) = 1 - exp(-x)
+        assert!(close(gamma_p(1.0, 0.5), 1.0 - (-0.5f64).exp()));
+        assert!(close(gamma_p(1.0, 5.0), 1.0 - (-5.0f64).exp()));
+        // I_x(a, 1) = x^a
+        assert!(close(beta_i(2.5, 1.0, 0.3), 0.3f64.powf(2.5)));
+        assert!(close(beta_i(2.5, 1.0, 0.9), 0.9f64.powf(2.5)));
+        assert!(close(erf(1.0), 0.8427007929497149));
+        assert!(close(erf(-2.0), -0.9953222650189527));
+    }
+}
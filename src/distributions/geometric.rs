@@ -0,0 +1,60 @@
+use crate::distributions::Distribution;
+use crate::error::RandError;
+use crate::rand::Rand;
+use crate::source::Source;
+
+/// A Geometric is the number of failures before the first success in
+/// independent trials that each succeed with probability p.
+#[derive(Clone, Copy, Debug)]
+pub struct Geometric {
+    ln_q: f64,
+}
+
+impl Geometric {
+    /// Creates a new Geometric distribution.
+    /// Requirements: 0 < p <= 1, otherwise `RandError::InvalidArgument`
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Geometric, Rand, RngSource};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     let retries = Geometric::new(0.25)?;
+    ///     println!("n: {}", r.sample(&retries));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(p: f64) -> Result<Geometric, RandError> {
+        if !(p > 0.0 && p <= 1.0) {
+            return Err(RandError::InvalidArgument("geometric: requires 0 < p <= 1"));
+        }
+        Ok(Geometric { ln_q: (-p).ln_1p() })
+    }
+}
+
+impl Distribution<u64> for Geometric {
+    fn sample<S: Source>(&self, r: &mut Rand<S>) -> u64 {
+        // Inverse of the CDF, 1 - (1-p)^(k+1). The float to integer
+        // conversion saturates for the rare huge values of tiny p.
+        ((-r.float64()).ln_1p() / self.ln_q).floor() as u64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Geometric;
+    use crate::distributions::fit::chi_square;
+
+    #[test]
+    fn geometric() {
+        for &p in &[0.9, 0.5, 0.1, 0.001, 1.0] {
+            let dist = Geometric::new(p).unwrap();
+            chi_square(4, dist, |k| p * (1.0 - p).powf(k as f64));
+        }
+        assert!(Geometric::new(0.0).is_err());
+        assert!(Geometric::new(1.5).is_err());
+    }
+}
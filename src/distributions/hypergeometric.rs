@@ -0,0 +1,179 @@
+use crate::distributions::gamma::ln_gamma;
+use crate::distributions::Distribution;
+use crate::error::RandError;
+use crate::rand::Rand;
+use crate::source::Source;
+
+/// A Hypergeometric is the number of successes in draws made without
+/// replacement from a population of total items, successes of which
+/// count as successes.
+///
+/// Few draws are simulated one by one, and otherwise the HRUA ratio of
+/// uniforms method is used, whose cost does not grow with the
+/// population.
+///
+/// See "Computer Generation of Hypergeometric Random Variates"
+/// (Stadlober, 1989)
+/// https://doi.org/10.1080/00949658908811207
+#[derive(Clone, Copy, Debug)]
+pub struct Hypergeometric {
+    total: u64,
+    successes: u64,
+    draws: u64,
+}
+
+impl Hypergeometric {
+    /// Creates a new Hypergeometric distribution.
+    /// Requirements: successes <= total and draws <= total, otherwise
+    /// `RandError::InvalidArgument` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Hypergeometric, Rand, RngSource};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     let defective = Hypergeometric::new(5000, 40, 200)?;
+    ///     println!("n: {}", r.sample(&defective));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(total: u64, successes: u64, draws: u64) -> Result<Hypergeometric, RandError> {
+        if successes > total || draws > total {
+            return Err(RandError::InvalidArgument(
+                "hypergeometric: requires successes <= total and draws <= total",
+            ));
+        }
+        Ok(Hypergeometric {
+            total,
+            successes,
+            draws,
+        })
+    }
+}
+
+impl Distribution<u64> for Hypergeometric {
+    fn sample<S: Source>(&self, r: &mut Rand<S>) -> u64 {
+        let (good, bad) = (self.successes, self.total - self.successes);
+        if self.draws >= 10 && self.draws <= self.total - 10 {
+            hrua(r, good, bad, self.draws)
+        } else {
+            urn(r, good, self.total, self.draws)
+        }
+    }
+}
+
+/// Draws items one at a time, at most half the population.
+fn urn<S: Source>(r: &mut Rand<S>, good: u64, total: u64, draws: u64) -> u64 {
+    // Drawing k items leaves the same count as drawing the other
+    // total-k, so draw the smaller number.
+    let mut left = draws.min(total - draws);
+    let mut remaining_total = total;
+    let mut remaining_good = good;
+    while left > 0 && remaining_good > 0 && remaining_total > remaining_good {
+        if r.uint64n(remaining_total) < remaining_good {
+            remaining_good -= 1;
+        }
+        remaining_total -= 1;
+        left -= 1;
+    }
+    if remaining_total == remaining_good {
+        // Only good items are left.
+        remaining_good -= left;
+    }
+    if draws > total / 2 {
+        remaining_good
+    } else {
+        good - remaining_good
+    }
+}
+
+fn ln_factorial(k: f64) -> f64 {
+    ln_gamma(k + 1.0)
+}
+
+/// HRUA, for draws in [10, total - 10].
+fn hrua<S: Source>(r: &mut Rand<S>, good: u64, bad: u64, draws: u64) -> u64 {
+    const D1: f64 = 1.7155277699214135;
+    const D2: f64 = 0.8989161620588988;
+    let popsize = good + bad;
+    let sample = draws.min(popsize - draws);
+    let min_gb = good.min(bad) as f64;
+    let max_gb = good.max(bad) as f64;
+    let (n, s) = (popsize as f64, sample as f64);
+
+    let p = min_gb / n;
+    let q = max_gb / n;
+    let a = s * p + 0.5;
+    let var = (n - s) * s * p * q / (n - 1.0);
+    let c = (var + 0.5).sqrt();
+    let h = D1 * c + D2;
+    let m = ((s + 1.0) * (min_gb + 1.0) / (n + 2.0)).floor();
+    let g = ln_factorial(m)
+        + ln_factorial(min_gb - m)
+        + ln_factorial(s - m)
+        + ln_factorial(max_gb - s + m);
+    let b = (s.min(min_gb) + 1.0).min((a + 16.0 * c).floor());
+
+    let k = loop {
+        let u = r.float64();
+        let v = r.float64();
+        let x = a + h * (v - 0.5) / u;
+        if !(0.0..b).contains(&x) {
+            continue;
+        }
+        let k = x.floor();
+        let t = g
+            - (ln_factorial(k)
+                + ln_factorial(min_gb - k)
+                + ln_factorial(s - k)
+                + ln_factorial(max_gb - s + k));
+        // Squeezes, then the exact test.
+        if u * (4.0 - u) - 3.0 <= t {
+            break k as u64;
+        }
+        if u * (u - t) >= 1.0 {
+            continue;
+        }
+        if 2.0 * u.ln() <= t {
+            break k as u64;
+        }
+    };
+    // Undo the reductions to the smaller group and fewer draws.
+    let k = if good > bad { sample - k } else { k };
+    if sample < draws {
+        good - k
+    } else {
+        k
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Hypergeometric;
+    use crate::distributions::fit::{chi_square, ln_choose};
+
+    #[test]
+    fn hypergeometric() {
+        let cases: Vec<(u64, u64, u64)> = vec![
+            (20, 7, 5),
+            (50, 30, 45),
+            (500, 100, 60),
+            (500, 400, 300),
+            (100000, 3000, 20000),
+        ];
+        for (total, good, draws) in cases {
+            let dist = Hypergeometric::new(total, good, draws).unwrap();
+            chi_square(5, dist, |k| {
+                if k > good || k > draws || draws - k > total - good {
+                    return 0.0;
+                }
+                (ln_choose(good, k) + ln_choose(total - good, draws - k) - ln_choose(total, draws))
+                    .exp()
+            });
+        }
+        assert!(Hypergeometric::new(10, 11, 5).is_err());
+        assert!(Hypergeometric::new(10, 5, 11).is_err());
+    }
+}
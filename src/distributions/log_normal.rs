@@ -0,0 +1,63 @@
+use crate::distributions::Distribution;
+use crate::error::RandError;
+use crate::rand::Rand;
+use crate::source::Source;
+
+/// A LogNormal is the distribution of e^X for X normally distributed
+/// with mean μ and standard deviation σ.
+#[derive(Clone, Copy, Debug)]
+pub struct LogNormal {
+    mu: f64,
+    sigma: f64,
+}
+
+impl LogNormal {
+    /// Creates a new LogNormal distribution.
+    /// Requirements: mu is finite and sigma >= 0, otherwise
+    /// `RandError::InvalidArgument` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{LogNormal, Rand, RngSource};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     let d = LogNormal::new(0.0, 0.25)?;
+    ///     println!("n: {}", r.sample(&d));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(mu: f64, sigma: f64) -> Result<LogNormal, RandError> {
+        if !(mu.is_finite() && sigma >= 0.0 && sigma.is_finite()) {
+            return Err(RandError::InvalidArgument(
+                "log normal: requires finite mu and sigma >= 0",
+            ));
+        }
+        Ok(LogNormal { mu, sigma })
+    }
+}
+
+impl Distribution<f64> for LogNormal {
+    fn sample<S: Source>(&self, r: &mut Rand<S>) -> f64 {
+        (self.mu + self.sigma * r.norm_float64()).exp()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LogNormal;
+    use crate::distributions::fit::{erf, ks};
+
+    #[test]
+    fn log_normal() {
+        for &(mu, sigma) in &[(0.0, 1.0), (2.0, 0.25), (-1.0, 2.0)] {
+            let dist = LogNormal::new(mu, sigma).unwrap();
+            ks(5, dist, |x: f64| {
+                0.5 + 0.5 * erf((x.ln() - mu) / (sigma * std::f64::consts::SQRT_2))
+            });
+        }
+        assert!(LogNormal::new(0.0, -1.0).is_err());
+        assert!(LogNormal::new(f64::NAN, 1.0).is_err());
+    }
+}
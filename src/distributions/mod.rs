@@ -0,0 +1,155 @@
+//! Probability distributions drawn from any `Rand`.
+//!
+//! Every distribution implements `Distribution` and is sampled through
+//! `Rand::sample` or `Rand::sample_iter`. The types are also exported
+//! from the crate root.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use srand::distributions::{Distribution, Uniform};
+//! use srand::{Rand, RngSource};
+//!
+//! fn main() -> Result<(), srand::RandError> {
+//!     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+//!     let die = Uniform::new_inclusive(1, 6)?;
+//!     println!("roll: {}", die.sample(&mut r));
+//!     Ok(())
+//! }
+//! ```
+
+use crate::rand::Rand;
+use crate::source::Source;
+use std::marker::PhantomData;
+
+mod beta;
+mod binomial;
+mod cauchy;
+mod chi_squared;
+#[cfg(test)]
+mod fit;
+mod gamma;
+mod geometric;
+mod hypergeometric;
+mod log_normal;
+mod negative_binomial;
+mod poisson;
+mod student_t;
+mod uniform;
+mod weibull;
+
+pub use beta::Beta;
+pub use binomial::Binomial;
+pub use cauchy::Cauchy;
+pub use chi_squared::ChiSquared;
+pub use gamma::Gamma;
+pub use geometric::Geometric;
+pub use hypergeometric::Hypergeometric;
+pub use log_normal::LogNormal;
+pub use negative_binomial::NegativeBinomial;
+pub use poisson::Poisson;
+pub use student_t::StudentT;
+pub use uniform::Uniform;
+pub use weibull::Weibull;
+
+/// Reports whether x is a valid positive, finite parameter.
+fn positive(x: f64) -> bool {
+    x > 0.0 && x.is_finite()
+}
+
+/// A Distribution produces values of type T from the uniformly
+/// distributed values of a Rand.
+///
+/// Implementations hold only their parameters, so one distribution can
+/// be shared by many generators and sampled through `Rand::sample` or
+/// `Rand::sample_iter`.
+pub trait Distribution<T> {
+    /// Returns a value drawn from the distribution.
+    fn sample<S: Source>(&self, r: &mut Rand<S>) -> T;
+}
+
+impl<T, D> Distribution<T> for &D
+where
+    D: Distribution<T>,
+{
+    fn sample<S: Source>(&self, r: &mut Rand<S>) -> T {
+        (*self).sample(r)
+    }
+}
+
+/// A SampleIter is the endless iterator returned by `Rand::sample_iter`.
+pub struct SampleIter<'a, D, S, T>
+where
+    S: Source,
+{
+    dist: D,
+    r: &'a mut Rand<S>,
+    _t: PhantomData<fn() -> T>,
+}
+
+impl<D, S, T> Iterator for SampleIter<'_, D, S, T>
+where
+    D: Distribution<T>,
+    S: Source,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        Some(self.dist.sample(self.r))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<S> Rand<S>
+where
+    S: Source,
+{
+    /// Returns a value drawn from dist.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Rand, RngSource, Uniform};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     let die = Uniform::new_inclusive(1, 6)?;
+    ///     println!("n: {}", r.sample(&die));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn sample<T, D>(&mut self, dist: &D) -> T
+    where
+        D: Distribution<T>,
+    {
+        dist.sample(self)
+    }
+
+    /// Returns an endless iterator of values drawn from dist.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Rand, RngSource, Uniform};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     let v: Vec<f64> = r.sample_iter(Uniform::new(-1.0, 1.0)?).take(10).collect();
+    ///     println!("v: {:?}", v);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn sample_iter<T, D>(&mut self, dist: D) -> SampleIter<'_, D, S, T>
+    where
+        D: Distribution<T>,
+    {
+        SampleIter {
+            dist,
+            r: self,
+            _t: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::distributions::gamma::standard_gamma;
+use crate::distributions::poisson::{poisson, LAMBDA_MAX};
+use crate::distributions::{positive, Distribution};
+use crate::error::RandError;
+use crate::rand::Rand;
+use crate::source::Source;
+
+/// A NegativeBinomial is the number of failures before the r-th
+/// success in independent trials that each succeed with probability p.
+/// r need not be an integer.
+///
+/// It is drawn as a Poisson whose mean is gamma distributed.
+#[derive(Clone, Copy, Debug)]
+pub struct NegativeBinomial {
+    r: f64,
+    p: f64,
+}
+
+impl NegativeBinomial {
+    /// Creates a new NegativeBinomial distribution.
+    /// Requirements: r > 0 and 0 < p <= 1, otherwise
+    /// `RandError::InvalidArgument` is returned. Like numpy, it also
+    /// rejects r and p whose Poisson means would routinely exceed the
+    /// largest one `Poisson` accepts: (1-p)/p · (r + 10·sqrt(r)) must be
+    /// at most 9.2e18.
+    pub fn new(r: f64, p: f64) -> Result<NegativeBinomial, RandError> {
+        if !(positive(r) && p > 0.0 && p <= 1.0) {
+            return Err(RandError::InvalidArgument(
+                "negative binomial: requires r > 0 and 0 < p <= 1",
+            ));
+        }
+        if (1.0 - p) / p * (r + 10.0 * r.sqrt()) > LAMBDA_MAX {
+            return Err(RandError::InvalidArgument(
+                "negative binomial: r too large or p too small",
+            ));
+        }
+        Ok(NegativeBinomial { r, p })
+    }
+}
+
+impl Distribution<u64> for NegativeBinomial {
+    fn sample<S: Source>(&self, r: &mut Rand<S>) -> u64 {
+        let lambda = standard_gamma(r, self.r) * (1.0 - self.p) / self.p;
+        poisson(r, lambda)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NegativeBinomial;
+    use crate::distributions::fit::chi_square;
+    use crate::distributions::gamma::ln_gamma;
+
+    #[test]
+    fn negative_binomial() {
+        for &(r, p) in &[(1.0, 0.5), (2.5, 0.2), (40.0, 0.7), (3.0, 1.0)] {
+            let dist = NegativeBinomial::new(r, p).unwrap();
+            chi_square(2, dist, |k| {
+                let k = k as f64;
+                let ln_coef = ln_gamma(k + r) - ln_gamma(k + 1.0) - ln_gamma(r);
+                if p == 1.0 {
+                    return if k == 0.0 { 1.0 } else { 0.0 };
+                }
+                (ln_coef + r * p.ln() + k * (1.0 - p).ln()).exp()
+            });
+        }
+        assert!(NegativeBinomial::new(0.0, 0.5).is_err());
+        assert!(NegativeBinomial::new(1.0, 0.0).is_err());
+        assert!(NegativeBinomial::new(1.0, 1.5).is_err());
+        assert!(NegativeBinomial::new(1.0, 1e-300).is_err());
+        assert!(NegativeBinomial::new(1e300, 0.5).is_err());
+    }
+}
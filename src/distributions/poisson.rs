@@ -0,0 +1,117 @@
+use crate::distributions::gamma::ln_gamma;
+use crate::distributions::{positive, Distribution};
+use crate::error::RandError;
+use crate::rand::Rand;
+use crate::source::Source;
+
+/// A Poisson is the Poisson distribution with mean λ, the number of
+/// events in an interval where they occur independently at rate λ.
+///
+/// Small means are drawn by multiplying uniforms, and means of 10 and
+/// more by the PTRS transformed rejection method, whose cost does not
+/// grow with λ.
+///
+/// See "The transformed rejection method for generating Poisson random
+/// variables" (Hörmann, 1993)
+/// https://doi.org/10.1016/0167-6687(93)90997-4
+#[derive(Clone, Copy, Debug)]
+pub struct Poisson {
+    lambda: f64,
+}
+
+/// The largest mean accepted, i64::MAX - 10·sqrt(i64::MAX) as in
+/// numpy, which keeps nearly every draw below i64::MAX.
+pub(crate) const LAMBDA_MAX: f64 = 9.223372006484771e18;
+
+impl Poisson {
+    /// Creates a new Poisson distribution with mean lambda.
+    /// Requirements: 0 < lambda <= 9.2e18, otherwise
+    /// `RandError::InvalidArgument` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Poisson, Rand, RngSource};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     let arrivals = Poisson::new(4.5)?;
+    ///     println!("n: {}", r.sample(&arrivals));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(lambda: f64) -> Result<Poisson, RandError> {
+        if !(positive(lambda) && lambda <= LAMBDA_MAX) {
+            return Err(RandError::InvalidArgument(
+                "poisson: requires 0 < lambda <= 9.2e18",
+            ));
+        }
+        Ok(Poisson { lambda })
+    }
+}
+
+impl Distribution<u64> for Poisson {
+    fn sample<S: Source>(&self, r: &mut Rand<S>) -> u64 {
+        poisson(r, self.lambda)
+    }
+}
+
+/// Returns a Poisson distributed u64 with mean lambda >= 0.
+pub(crate) fn poisson<S: Source>(r: &mut Rand<S>, lambda: f64) -> u64 {
+    if lambda < 10.0 {
+        // Count uniforms until their product drops below e^-λ.
+        let limit = (-lambda).exp();
+        let mut k = 0;
+        let mut prod = r.float64();
+        while prod > limit {
+            k += 1;
+            prod *= r.float64();
+        }
+        return k;
+    }
+    let slam = lambda.sqrt();
+    let loglam = lambda.ln();
+    let b = 0.931 + 2.53 * slam;
+    let a = -0.059 + 0.02483 * b;
+    let invalpha = 1.1239 + 1.1328 / (b - 3.4);
+    let vr = 0.9277 - 3.6224 / (b - 2.0);
+    loop {
+        let u = r.float64() - 0.5;
+        let v = r.float64();
+        let us = 0.5 - u.abs();
+        let k = ((2.0 * a / us + b) * u + lambda + 0.43).floor();
+        if us >= 0.07 && v <= vr {
+            return k as u64;
+        }
+        if k < 0.0 || (us < 0.013 && v > us) {
+            continue;
+        }
+        if v.ln() + invalpha.ln() - (a / (us * us) + b).ln()
+            <= -lambda + k * loglam - ln_gamma(k + 1.0)
+        {
+            return k as u64;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Poisson;
+    use crate::distributions::fit::chi_square;
+    use crate::distributions::gamma::ln_gamma;
+
+    #[test]
+    fn poisson() {
+        for &lambda in &[0.5, 3.0, 9.9, 10.0, 75.0, 20000.0] {
+            let dist = Poisson::new(lambda).unwrap();
+            chi_square(1, dist, |k| {
+                let k = k as f64;
+                (k * lambda.ln() - lambda - ln_gamma(k + 1.0)).exp()
+            });
+        }
+        assert!(Poisson::new(0.0).is_err());
+        assert!(Poisson::new(f64::NAN).is_err());
+        assert!(Poisson::new(1e19).is_err());
+        assert!(Poisson::new(f64::MAX).is_err());
+    }
+}
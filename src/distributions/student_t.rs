@@ -0,0 +1,54 @@
+use crate::distributions::gamma::standard_gamma;
+use crate::distributions::{positive, Distribution};
+use crate::error::RandError;
+use crate::rand::Rand;
+use crate::source::Source;
+
+/// A StudentT is Student's t-distribution with ν degrees of freedom.
+#[derive(Clone, Copy, Debug)]
+pub struct StudentT {
+    nu: f64,
+}
+
+impl StudentT {
+    /// Creates a new StudentT distribution with nu degrees of freedom.
+    /// Requirements: nu > 0, otherwise `RandError::InvalidArgument` is
+    /// returned.
+    pub fn new(nu: f64) -> Result<StudentT, RandError> {
+        if !positive(nu) {
+            return Err(RandError::InvalidArgument("student t: requires nu > 0"));
+        }
+        Ok(StudentT { nu })
+    }
+}
+
+impl Distribution<f64> for StudentT {
+    fn sample<S: Source>(&self, r: &mut Rand<S>) -> f64 {
+        // Z / sqrt(V/ν) for Z ~ N(0,1) and V ~ ChiSquared(ν).
+        let z = r.norm_float64();
+        let v = standard_gamma(r, 0.5 * self.nu) * 2.0;
+        z / (v / self.nu).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::StudentT;
+    use crate::distributions::fit::{beta_i, ks};
+
+    #[test]
+    fn student_t() {
+        for &nu in &[1.0, 2.5, 10.0, 100.0] {
+            let dist = StudentT::new(nu).unwrap();
+            ks(4, dist, |t| {
+                let tail = 0.5 * beta_i(0.5 * nu, 0.5, nu / (nu + t * t));
+                if t > 0.0 {
+                    1.0 - tail
+                } else {
+                    tail
+                }
+            });
+        }
+        assert!(StudentT::new(-1.0).is_err());
+    }
+}
@@ -0,0 +1,145 @@
+use crate::distributions::Distribution;
+use crate::error::RandError;
+use crate::rand::Rand;
+use crate::range::SampleRange;
+use crate::source::Source;
+use std::ops::{Range, RangeInclusive};
+
+/// A Uniform draws values uniformly from an interval.
+///
+/// Values are drawn like `Rand::range` over the same interval. So
+/// integers are drawn like `Rand::int64n` when the interval fits in an
+/// i64, and `Uniform::new(0, n)` yields the same stream as
+/// `int64n(n)`. Floats are drawn like `Rand::float64` and
+/// `Rand::float32`, so `Uniform::new(0.0, 1.0)` yields the same stream
+/// as `float64()`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use srand::{Distribution, Rand, RngSource, Source, Uniform};
+///
+/// fn total<T, D, S>(r: &mut Rand<S>, dist: D, n: usize) -> T
+/// where
+///     T: std::iter::Sum<T>,
+///     D: Distribution<T>,
+///     S: Source,
+/// {
+///     r.sample_iter(dist).take(n).sum()
+/// }
+///
+/// fn main() -> Result<(), srand::RandError> {
+///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+///     println!("n: {}", total(&mut r, Uniform::new(0u32, 10)?, 100));
+///     println!("n: {}", total(&mut r, Uniform::new(0.0, 10.0)?, 100));
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Uniform<T> {
+    low: T,
+    high: T,
+    inclusive: bool,
+}
+
+impl<T> Uniform<T>
+where
+    Range<T>: SampleRange<T>,
+    RangeInclusive<T>: SampleRange<T>,
+{
+    /// Creates a new Uniform over the half-open interval [low, high).
+    /// It returns `RandError::InvalidArgument` if the interval is empty.
+    pub fn new(low: T, high: T) -> Result<Uniform<T>, RandError> {
+        let range = low..high;
+        if SampleRange::is_empty(&range) {
+            return Err(RandError::InvalidArgument("uniform: requires low < high"));
+        }
+        Ok(Uniform {
+            low: range.start,
+            high: range.end,
+            inclusive: false,
+        })
+    }
+
+    /// Creates a new Uniform over the closed interval [low, high].
+    /// It returns `RandError::InvalidArgument` if the interval is empty.
+    pub fn new_inclusive(low: T, high: T) -> Result<Uniform<T>, RandError> {
+        let range = low..=high;
+        if SampleRange::is_empty(&range) {
+            return Err(RandError::InvalidArgument("uniform: requires low <= high"));
+        }
+        let (low, high) = range.into_inner();
+        Ok(Uniform {
+            low,
+            high,
+            inclusive: true,
+        })
+    }
+}
+
+impl<T> Distribution<T> for Uniform<T>
+where
+    T: Copy,
+    Range<T>: SampleRange<T>,
+    RangeInclusive<T>: SampleRange<T>,
+{
+    fn sample<S: Source>(&self, r: &mut Rand<S>) -> T {
+        if self.inclusive {
+            (self.low..=self.high).sample(r)
+        } else {
+            (self.low..self.high).sample(r)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Uniform;
+    use crate::rand::Rand;
+    use crate::source::RngSource;
+
+    #[test]
+    fn examples() {
+        let mut r: Rand<_> = Rand::new(RngSource::new(1));
+        let mut s: Rand<_> = Rand::new(RngSource::new(1));
+        let ints = Uniform::new(0i64, 100).unwrap();
+        let floats = Uniform::new(0.0, 1.0).unwrap();
+        for _i in 0..100 {
+            assert_eq!(s.int64n(100), r.sample(&ints));
+            assert_eq!(s.float64(), r.sample(&floats));
+        }
+        for _i in 0..100 {
+            assert_eq!(
+                s.range(-7i16..=90),
+                r.sample(&Uniform::new_inclusive(-7i16, 90).unwrap())
+            );
+        }
+
+        let dice: Vec<u8> = r
+            .sample_iter(Uniform::new_inclusive(1u8, 6).unwrap())
+            .take(1000)
+            .collect();
+        assert!(dice.iter().all(|d| (1..=6).contains(d)));
+        for face in 1..=6 {
+            assert!(dice.contains(&face));
+        }
+
+        for _i in 0..1000 {
+            let v = r.sample(&Uniform::new(-3i32, 3).unwrap());
+            assert!((-3..3).contains(&v));
+            let v = r.sample(&Uniform::new(u64::MAX - 2, u64::MAX).unwrap());
+            assert!((u64::MAX - 2..u64::MAX).contains(&v));
+            let v = r.sample(&Uniform::new(-1.5f32, -0.5).unwrap());
+            assert!((-1.5..-0.5).contains(&v));
+        }
+        r.sample(&Uniform::new_inclusive(i64::MIN, i64::MAX).unwrap());
+        let v = r.sample(&Uniform::new(i128::MIN, i128::MIN + 3).unwrap());
+        assert!(v < i128::MIN + 3);
+        r.sample(&Uniform::new_inclusive(0u128, u128::MAX).unwrap());
+        assert_eq!(7, r.sample(&Uniform::new_inclusive(7, 7).unwrap()));
+
+        assert!(Uniform::new(5, 5).is_err());
+        assert!(Uniform::new_inclusive(5, 4).is_err());
+        assert!(Uniform::new(1.0, f64::NAN).is_err());
+    }
+}
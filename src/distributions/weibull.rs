@@ -0,0 +1,65 @@
+use crate::distributions::{positive, Distribution};
+use crate::error::RandError;
+use crate::rand::Rand;
+use crate::source::Source;
+
+/// A Weibull is the Weibull distribution with scale λ and shape k,
+/// whose CDF is 1 - e^(-(x/λ)^k) for x >= 0.
+#[derive(Clone, Copy, Debug)]
+pub struct Weibull {
+    scale: f64,
+    inv_shape: f64,
+}
+
+impl Weibull {
+    /// Creates a new Weibull distribution.
+    /// Requirements: scale > 0 and shape > 0, otherwise
+    /// `RandError::InvalidArgument` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Rand, RngSource, Weibull};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     let d = Weibull::new(1.0, 1.5)?;
+    ///     println!("n: {}", r.sample(&d));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(scale: f64, shape: f64) -> Result<Weibull, RandError> {
+        if !positive(scale) || !positive(shape) {
+            return Err(RandError::InvalidArgument(
+                "weibull: requires scale > 0 and shape > 0",
+            ));
+        }
+        Ok(Weibull {
+            scale,
+            inv_shape: 1.0 / shape,
+        })
+    }
+}
+
+impl Distribution<f64> for Weibull {
+    fn sample<S: Source>(&self, r: &mut Rand<S>) -> f64 {
+        // Inverse of the CDF; 1 - U is in (0, 1], so the log is finite.
+        self.scale * (-(1.0 - r.float64()).ln()).powf(self.inv_shape)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Weibull;
+    use crate::distributions::fit::ks;
+
+    #[test]
+    fn weibull() {
+        for &(scale, shape) in &[(1.0, 1.0), (2.0, 0.5), (0.5, 5.0)] {
+            let dist = Weibull::new(scale, shape).unwrap();
+            ks(7, dist, |x: f64| 1.0 - (-(x / scale).powf(shape)).exp());
+        }
+        assert!(Weibull::new(1.0, 0.0).is_err());
+        assert!(Weibull::new(-1.0, 1.0).is_err());
+    }
+}
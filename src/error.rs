@@ -0,0 +1,44 @@
+use std::fmt;
+use std::io;
+
+/// The error type for the fallible operations of this crate.
+#[derive(Debug)]
+pub enum RandError {
+    /// An argument was outside the domain the operation is defined on.
+    InvalidArgument(&'static str),
+    /// Reading from the operating system's source of randomness failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for RandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RandError::InvalidArgument(msg) => write!(f, "invalid argument: {}", msg),
+            RandError::Io(err) => write!(f, "io error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for RandError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RandError::InvalidArgument(_) => None,
+            RandError::Io(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for RandError {
+    fn from(err: io::Error) -> RandError {
+        RandError::Io(err)
+    }
+}
+
+impl From<RandError> for io::Error {
+    fn from(err: RandError) -> io::Error {
+        match err {
+            RandError::InvalidArgument(msg) => io::Error::new(io::ErrorKind::InvalidInput, msg),
+            RandError::Io(err) => err,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Detects fork(2), so that sources holding buffered or secret state
+//! do not hand the same values to a parent and its child.
+
+/// Returns a value that differs in a child process from the value its
+/// parent saw before forking. Comparing it with a saved value tells
+/// whether the process has forked since it was saved.
+///
+/// On Linux and Android it is a counter bumped by a `pthread_atfork`
+/// child handler, so the check costs an atomic load rather than a
+/// `getpid` syscall. Children created by a raw `clone` syscall, which
+/// skips the handlers, are not detected. Elsewhere it is the process
+/// id.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) fn generation() -> u64 {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Once;
+
+    static FORKS: AtomicU64 = AtomicU64::new(0);
+    static REGISTER: Once = Once::new();
+
+    unsafe extern "C" fn child() {
+        FORKS.fetch_add(1, Ordering::Relaxed);
+    }
+
+    REGISTER.call_once(|| unsafe {
+        libc::pthread_atfork(None, None, Some(child));
+    });
+    FORKS.load(Ordering::Relaxed)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub(crate) fn generation() -> u64 {
+    std::process::id() as u64
+}
+
+#[cfg(all(test, any(target_os = "linux", target_os = "android")))]
+mod test {
+    use super::generation;
+
+    #[test]
+    fn child_sees_new_generation() {
+        let before = generation();
+        unsafe {
+            let pid = libc::fork();
+            assert!(pid >= 0);
+            if pid == 0 {
+                // Only async-signal-safe calls are allowed here.
+                libc::_exit(if generation() != before { 0 } else { 1 });
+            }
+            let mut status = 0;
+            assert_eq!(pid, libc::waitpid(pid, &mut status, 0));
+            assert!(libc::WIFEXITED(status));
+            assert_eq!(0, libc::WEXITSTATUS(status));
+        }
+        assert_eq!(before, generation());
+    }
+}
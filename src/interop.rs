@@ -0,0 +1,139 @@
+use crate::rand::Rand;
+use crate::source::{LockedSource, RngSource, Source};
+use rand_core::{RngCore, SeedableRng};
+
+macro_rules! impl_rng_core {
+    ($($ty:ty),*) => {$(
+        impl RngCore for $ty {
+            fn next_u32(&mut self) -> u32 {
+                Source::uint32(self)
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                Source::uint64(self)
+            }
+
+            fn fill_bytes(&mut self, dst: &mut [u8]) {
+                Source::fill_bytes(self, dst)
+            }
+        }
+    )*};
+}
+
+impl_rng_core!(RngSource, LockedSource);
+
+impl<S: Source> RngCore for Rand<S> {
+    fn next_u32(&mut self) -> u32 {
+        self.uint32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.uint64()
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        Rand::fill_bytes(self, dst)
+    }
+}
+
+/// The seed is a little-endian i64, so `from_seed` and
+/// `seed_from_u64` give the same stream as `RngSource::new`.
+impl SeedableRng for RngSource {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: [u8; 8]) -> RngSource {
+        RngSource::new(i64::from_le_bytes(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> RngSource {
+        RngSource::new(state as i64)
+    }
+}
+
+/// A FromRngCore adapts any `rand_core::RngCore` into a Source, so
+/// generators from the rand ecosystem can drive a `Rand`.
+///
+/// An RngCore cannot be reseeded through its trait, so `seed` does
+/// nothing; seed the wrapped generator before adapting it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use srand::{FromRngCore, Rand};
+///
+/// fn roll<R: rand_core::RngCore>(rng: R) -> i64 {
+///     let mut r: Rand<_> = Rand::new(FromRngCore::new(rng));
+///     r.int64n(6) + 1
+/// }
+/// ```
+#[derive(Clone)]
+pub struct FromRngCore<R> {
+    rng: R,
+}
+
+impl<R: RngCore> FromRngCore<R> {
+    /// Creates a new FromRngCore that draws its values from rng.
+    pub fn new(rng: R) -> FromRngCore<R> {
+        FromRngCore { rng }
+    }
+
+    /// Returns the wrapped generator.
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+}
+
+impl<R: RngCore> Source for FromRngCore<R> {
+    /// Does nothing, see `FromRngCore`.
+    fn seed(&mut self, _seed: i64) {}
+
+    fn uint32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn uint64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn int64(&mut self) -> i64 {
+        (self.rng.next_u64() & !(1 << 63)) as i64
+    }
+
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        self.rng.fill_bytes(buf)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FromRngCore;
+    use crate::rand::Rand;
+    use crate::source::{LockedSource, RngSource};
+    use rand_core::{RngCore, SeedableRng};
+
+    fn draw<R: RngCore>(rng: &mut R) -> Vec<u64> {
+        (0..20).map(|_| rng.next_u64()).collect()
+    }
+
+    #[test]
+    fn rng_core() {
+        let expected = draw(&mut Rand::new(RngSource::new(1)));
+        assert_eq!(expected, draw(&mut RngSource::seed_from_u64(1)));
+        assert_eq!(
+            expected,
+            draw(&mut RngSource::from_seed(1i64.to_le_bytes()))
+        );
+        assert_eq!(expected, draw(&mut LockedSource::new(1)));
+
+        let mut a: Rand<_> = Rand::new(RngSource::new(1));
+        let mut b: Rand<_> = Rand::new(FromRngCore::new(RngSource::new(1)));
+        for _i in 0..20 {
+            assert_eq!(a.int64n(1000), b.int64n(1000));
+            assert_eq!(a.uint32(), b.uint32());
+        }
+        let (mut x, mut y) = ([0u8; 13], [0u8; 13]);
+        a.fill_bytes(&mut x);
+        RngCore::fill_bytes(&mut b, &mut y);
+        assert_eq!(x, y);
+    }
+}
@@ -1,9 +1,47 @@
+mod chacha20;
+mod chacha8;
+mod crypto;
+pub mod distributions;
+mod error;
+mod exp;
+mod fork;
+#[cfg(feature = "rand_core")]
+mod interop;
+mod mt19937;
+mod normal;
+mod pcg;
+mod philox;
 mod rand;
+mod range;
 mod reader;
+mod reseeding;
+#[cfg(feature = "serde")]
+mod serde_util;
 mod source;
+mod splitmix;
 mod thread_local;
+mod xoshiro;
+mod zipf;
 
+pub use chacha20::ChaCha20Source;
+pub use chacha8::ChaCha8Source;
+pub use crypto::{CryptoSafe, CryptoSource};
+pub use distributions::{
+    Beta, Binomial, Cauchy, ChiSquared, Distribution, Gamma, Geometric, Hypergeometric, LogNormal,
+    NegativeBinomial, Poisson, SampleIter, StudentT, Uniform, Weibull,
+};
+pub use error::RandError;
+#[cfg(feature = "rand_core")]
+pub use interop::FromRngCore;
+pub use mt19937::{Mt19937Source, Mt19937_64Source};
+pub use pcg::PcgSource;
+pub use philox::{philox, PhiloxSource};
 pub use rand::Rand;
+pub use range::SampleRange;
 pub use reader::{read, Reader};
+pub use reseeding::ReseedingSource;
 pub use source::{LockedSource, RngSource, Source};
+pub use splitmix::{SeedExpander, SplitMix64};
 pub use thread_local::ThreadLocal;
+pub use xoshiro::{Xoroshiro128Plus, Xoshiro256StarStar};
+pub use zipf::Zipf;
@@ -0,0 +1,416 @@
+use crate::error::RandError;
+use crate::reader;
+use crate::source::Source;
+
+const N: usize = 624;
+const M: usize = 397;
+const MATRIX_A: u32 = 0x9908b0df;
+const UPPER_MASK: u32 = 0x80000000;
+const LOWER_MASK: u32 = 0x7fffffff;
+
+/// A Mt19937Source is the 32-bit Mersenne Twister MT19937.
+///
+/// Seeded with `new` it produces the same values as C++'s
+/// `std::mt19937(seed)` and numpy's `RandomState(seed)`; seeded with
+/// `from_array` it matches `init_by_array` of the reference
+/// implementation, which Python's `random.seed` uses.
+///
+/// `Rand::uint32` returns the raw 32-bit outputs, while uint64 joins
+/// two consecutive outputs, the first one in the upper half.
+///
+/// See http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/emt.html
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Mt19937State")
+)]
+pub struct Mt19937Source {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::array"))]
+    mt: [u32; N],
+    mti: usize,
+}
+
+impl Mt19937Source {
+    /// Creates a new Mt19937Source seeded like `std::mt19937(seed)`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Mt19937Source, Rand};
+    ///
+    /// fn main() {
+    ///     let mut r: Rand<_> = Rand::new(Mt19937Source::new(5489));
+    ///     println!("n: {}", r.uint32());
+    /// }
+    /// ```
+    pub fn new(seed: u32) -> Mt19937Source {
+        let mut src = Mt19937Source { mt: [0; N], mti: N };
+        src.init_genrand(seed);
+        src
+    }
+
+    /// Creates a new Mt19937Source seeded like `init_by_array(key)`.
+    pub fn from_array(key: &[u32]) -> Mt19937Source {
+        let mut src = Mt19937Source::new(19650218);
+        let mt = &mut src.mt;
+        let (mut i, mut j) = (1, 0);
+        for _k in 0..N.max(key.len()) {
+            let x = mt[i - 1] ^ (mt[i - 1] >> 30);
+            mt[i] = (mt[i] ^ x.wrapping_mul(1664525))
+                .wrapping_add(key.get(j).copied().unwrap_or(0))
+                .wrapping_add(j as u32);
+            i += 1;
+            j += 1;
+            if i >= N {
+                mt[0] = mt[N - 1];
+                i = 1;
+            }
+            if j >= key.len() {
+                j = 0;
+            }
+        }
+        for _k in 0..N - 1 {
+            let x = mt[i - 1] ^ (mt[i - 1] >> 30);
+            mt[i] = (mt[i] ^ x.wrapping_mul(1566083941)).wrapping_sub(i as u32);
+            i += 1;
+            if i >= N {
+                mt[0] = mt[N - 1];
+                i = 1;
+            }
+        }
+        // MSB is 1; assuring non-zero initial array
+        mt[0] = 0x80000000;
+        src
+    }
+
+    /// Creates a new Mt19937Source seeded by init_by_array with 256
+    /// bits read from the system's source of randomness.
+    pub fn from_entropy() -> Result<Mt19937Source, RandError> {
+        let mut words = [0u64; 4];
+        reader::read_words(&mut words)?;
+        let mut key = [0u32; 8];
+        for (i, word) in words.iter().enumerate() {
+            key[2 * i] = *word as u32;
+            key[2 * i + 1] = (*word >> 32) as u32;
+        }
+        Ok(Mt19937Source::from_array(&key))
+    }
+
+    fn init_genrand(&mut self, seed: u32) {
+        self.mt[0] = seed;
+        for i in 1..N {
+            let x = self.mt[i - 1] ^ (self.mt[i - 1] >> 30);
+            self.mt[i] = x.wrapping_mul(1812433253).wrapping_add(i as u32);
+        }
+        self.mti = N;
+    }
+
+    /// Returns a f64 in [0.0,1.0) with 53-bit resolution, built from two
+    /// outputs the way numpy's `random_sample` and Python's
+    /// `random.random` do.
+    pub fn genrand_res53(&mut self) -> f64 {
+        let a = self.uint32() >> 5;
+        let b = self.uint32() >> 6;
+        (a as f64 * 67108864.0 + b as f64) * (1.0 / 9007199254740992.0)
+    }
+
+    fn generate(&mut self) {
+        for kk in 0..N {
+            let y = (self.mt[kk] & UPPER_MASK) | (self.mt[(kk + 1) % N] & LOWER_MASK);
+            let mag = if y & 1 == 0 { 0 } else { MATRIX_A };
+            self.mt[kk] = self.mt[(kk + M) % N] ^ (y >> 1) ^ mag;
+        }
+        self.mti = 0;
+    }
+}
+
+impl Source for Mt19937Source {
+    /// Reseeds like `std::mt19937(seed as u32)`.
+    fn seed(&mut self, seed: i64) {
+        self.init_genrand(seed as u32);
+    }
+
+    fn uint32(&mut self) -> u32 {
+        if self.mti >= N {
+            self.generate();
+        }
+        let mut y = self.mt[self.mti];
+        self.mti += 1;
+        // Tempering
+        y ^= y >> 11;
+        y ^= (y << 7) & 0x9d2c5680;
+        y ^= (y << 15) & 0xefc60000;
+        y ^ (y >> 18)
+    }
+
+    fn uint64(&mut self) -> u64 {
+        let hi = self.uint32() as u64;
+        hi << 32 | self.uint32() as u64
+    }
+
+    fn int64(&mut self) -> i64 {
+        (self.uint64() & !(1 << 63)) as i64
+    }
+}
+
+const NN: usize = 312;
+const MM: usize = 156;
+const MATRIX_A_64: u64 = 0xb5026f5aa96619e9;
+// Most significant 33 bits
+const UM: u64 = 0xffffffff80000000;
+// Least significant 31 bits
+const LM: u64 = 0x7fffffff;
+
+/// A Mt19937_64Source is the 64-bit Mersenne Twister MT19937-64.
+///
+/// Seeded with `new` it produces the same values as C++'s
+/// `std::mt19937_64(seed)`; seeded with `from_array` it matches
+/// `init_by_array64` of the reference implementation.
+///
+/// See http://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/emt64.html
+#[allow(non_camel_case_types)]
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Mt19937_64State")
+)]
+pub struct Mt19937_64Source {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_util::array"))]
+    mt: [u64; NN],
+    mti: usize,
+}
+
+impl Mt19937_64Source {
+    /// Creates a new Mt19937_64Source seeded like `std::mt19937_64(seed)`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Mt19937_64Source, Rand};
+    ///
+    /// fn main() {
+    ///     let mut r: Rand<_> = Rand::new(Mt19937_64Source::new(5489));
+    ///     println!("n: {}", r.uint64());
+    /// }
+    /// ```
+    pub fn new(seed: u64) -> Mt19937_64Source {
+        let mut src = Mt19937_64Source {
+            mt: [0; NN],
+            mti: NN,
+        };
+        src.init_genrand64(seed);
+        src
+    }
+
+    /// Creates a new Mt19937_64Source seeded like `init_by_array64(key)`.
+    pub fn from_array(key: &[u64]) -> Mt19937_64Source {
+        let mut src = Mt19937_64Source::new(19650218);
+        let mt = &mut src.mt;
+        let (mut i, mut j) = (1, 0);
+        for _k in 0..NN.max(key.len()) {
+            let x = mt[i - 1] ^ (mt[i - 1] >> 62);
+            mt[i] = (mt[i] ^ x.wrapping_mul(3935559000370003845))
+                .wrapping_add(key.get(j).copied().unwrap_or(0))
+                .wrapping_add(j as u64);
+            i += 1;
+            j += 1;
+            if i >= NN {
+                mt[0] = mt[NN - 1];
+                i = 1;
+            }
+            if j >= key.len() {
+                j = 0;
+            }
+        }
+        for _k in 0..NN - 1 {
+            let x = mt[i - 1] ^ (mt[i - 1] >> 62);
+            mt[i] = (mt[i] ^ x.wrapping_mul(2862933555777941757)).wrapping_sub(i as u64);
+            i += 1;
+            if i >= NN {
+                mt[0] = mt[NN - 1];
+                i = 1;
+            }
+        }
+        // MSB is 1; assuring non-zero initial array
+        mt[0] = 1 << 63;
+        src
+    }
+
+    /// Creates a new Mt19937_64Source seeded by init_by_array64 with 256
+    /// bits read from the system's source of randomness.
+    pub fn from_entropy() -> Result<Mt19937_64Source, RandError> {
+        let mut key = [0u64; 4];
+        reader::read_words(&mut key)?;
+        Ok(Mt19937_64Source::from_array(&key))
+    }
+
+    fn init_genrand64(&mut self, seed: u64) {
+        self.mt[0] = seed;
+        for i in 1..NN {
+            let x = self.mt[i - 1] ^ (self.mt[i - 1] >> 62);
+            self.mt[i] = x.wrapping_mul(6364136223846793005).wrapping_add(i as u64);
+        }
+        self.mti = NN;
+    }
+
+    fn generate(&mut self) {
+        for i in 0..NN {
+            let x = (self.mt[i] & UM) | (self.mt[(i + 1) % NN] & LM);
+            let mag = if x & 1 == 0 { 0 } else { MATRIX_A_64 };
+            self.mt[i] = self.mt[(i + MM) % NN] ^ (x >> 1) ^ mag;
+        }
+        self.mti = 0;
+    }
+}
+
+impl Source for Mt19937_64Source {
+    /// Reseeds like `std::mt19937_64(seed as u64)`.
+    fn seed(&mut self, seed: i64) {
+        self.init_genrand64(seed as u64);
+    }
+
+    fn uint64(&mut self) -> u64 {
+        if self.mti >= NN {
+            self.generate();
+        }
+        let mut x = self.mt[self.mti];
+        self.mti += 1;
+        // Tempering
+        x ^= (x >> 29) & 0x5555555555555555;
+        x ^= (x << 17) & 0x71d67fffeda60000;
+        x ^= (x << 37) & 0xfff7eee000000000;
+        x ^ (x >> 43)
+    }
+
+    fn int64(&mut self) -> i64 {
+        (self.uint64() & !(1 << 63)) as i64
+    }
+}
+
+/// The serialized form of a Mt19937Source, checked before use.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct Mt19937State {
+    #[serde(with = "crate::serde_util::array")]
+    mt: [u32; N],
+    mti: usize,
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<Mt19937State> for Mt19937Source {
+    type Error = RandError;
+
+    fn try_from(s: Mt19937State) -> Result<Mt19937Source, RandError> {
+        // An all-zero state only ever produces zeros.
+        if s.mti > N || s.mt.iter().all(|&x| x == 0) {
+            return Err(RandError::InvalidArgument("invalid Mt19937Source state"));
+        }
+        Ok(Mt19937Source {
+            mt: s.mt,
+            mti: s.mti,
+        })
+    }
+}
+
+/// The serialized form of a Mt19937_64Source, checked before use.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[allow(non_camel_case_types)]
+struct Mt19937_64State {
+    #[serde(with = "crate::serde_util::array")]
+    mt: [u64; NN],
+    mti: usize,
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<Mt19937_64State> for Mt19937_64Source {
+    type Error = RandError;
+
+    fn try_from(s: Mt19937_64State) -> Result<Mt19937_64Source, RandError> {
+        if s.mti > NN || s.mt.iter().all(|&x| x == 0) {
+            return Err(RandError::InvalidArgument("invalid Mt19937_64Source state"));
+        }
+        Ok(Mt19937_64Source {
+            mt: s.mt,
+            mti: s.mti,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Mt19937Source, Mt19937_64Source};
+    use crate::rand::Rand;
+    use crate::source::Source;
+
+    #[test]
+    fn mt19937() {
+        // std::mt19937 with the default seed.
+        let expected: Vec<u32> = vec![3499211612, 581869302, 3890346734, 3586334585, 545404204];
+        let mut r: Rand<_> = Rand::new(Mt19937Source::new(5489));
+        let mut get = vec![];
+        for _i in 0..5 {
+            get.push(r.uint32());
+        }
+        assert_eq!(expected, get);
+        for _i in 5..9999 {
+            r.uint32();
+        }
+        assert_eq!(4123659995, r.uint32());
+
+        // mt19937ar.out of the reference implementation.
+        let expected: Vec<u32> = vec![1067595299, 955945823, 477289528, 4107218783, 4228976476];
+        let mut src = Mt19937Source::from_array(&[0x123, 0x234, 0x345, 0x456]);
+        let mut get = vec![];
+        for _i in 0..5 {
+            get.push(src.uint32());
+        }
+        assert_eq!(expected, get);
+
+        // numpy: np.random.seed(0); np.random.random_sample()
+        assert_eq!(0.5488135039273248, Mt19937Source::new(0).genrand_res53());
+        // Python: random.seed(12345); random.getrandbits(32); random.random()
+        let mut src = Mt19937Source::from_array(&[12345]);
+        assert_eq!(1789368711, src.uint32());
+        assert_eq!(0.7326852754516092, src.genrand_res53());
+    }
+
+    #[test]
+    fn mt19937_64() {
+        // std::mt19937_64 with the default seed.
+        let expected: Vec<u64> = vec![
+            14514284786278117030,
+            4620546740167642908,
+            13109570281517897720,
+            17462938647148434322,
+            355488278567739596,
+        ];
+        let mut r: Rand<_> = Rand::new(Mt19937_64Source::new(5489));
+        let mut get = vec![];
+        for _i in 0..5 {
+            get.push(r.uint64());
+        }
+        assert_eq!(expected, get);
+        for _i in 5..9999 {
+            r.uint64();
+        }
+        assert_eq!(9981545732273789042, r.uint64());
+
+        // mt19937-64.out of the reference implementation.
+        let expected: Vec<u64> = vec![
+            7266447313870364031,
+            4946485549665804864,
+            16945909448695747420,
+            16394063075524226720,
+            4873882236456199058,
+        ];
+        let mut src = Mt19937_64Source::from_array(&[0x12345, 0x23456, 0x34567, 0x45678]);
+        let mut get = vec![];
+        for _i in 0..5 {
+            get.push(src.uint64());
+        }
+        assert_eq!(expected, get);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::error::RandError;
+use crate::reader;
+use crate::source::Source;
+use crate::splitmix::SeedExpander;
+
+/// A PcgSource is a PCG generator with 128 bits of internal state.
+///
+/// It produces the same stream as `PCG` from Go's math/rand/v2: the
+/// state advances as a 128-bit LCG and each output is scrambled with
+/// the DXSM "double xorshift multiply" permutation.
+///
+/// With the `serde` feature it serializes as the bytes of `state`, so
+/// the serde form is the same as Go's `MarshalBinary`.
+///
+/// See https://github.com/imneme/pcg-cpp/blob/428802d1a5/include/pcg_random.hpp#L1015
+#[derive(Clone)]
+pub struct PcgSource {
+    hi: u64,
+    lo: u64,
+}
+
+impl PcgSource {
+    /// Creates a new PcgSource seeded with the given values.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::PcgSource;
+    ///
+    /// fn main() {
+    ///     let src = PcgSource::new(1, 2);
+    /// }
+    /// ```
+    pub fn new(seed1: u64, seed2: u64) -> PcgSource {
+        PcgSource {
+            hi: seed1,
+            lo: seed2,
+        }
+    }
+
+    /// Creates a new PcgSource seeded from the system's source of
+    /// randomness.
+    pub fn from_entropy() -> Result<PcgSource, RandError> {
+        let mut seed = [0u64; 2];
+        reader::read_words(&mut seed)?;
+        Ok(PcgSource::new(seed[0], seed[1]))
+    }
+
+    /// Returns the generator's state in the encoding of Go's
+    /// `PCG.MarshalBinary`: "pcg:" followed by the two state words as
+    /// big-endian u64s.
+    pub fn state(&self) -> Vec<u8> {
+        let mut b = Vec::with_capacity(20);
+        b.extend_from_slice(b"pcg:");
+        b.extend_from_slice(&self.hi.to_be_bytes());
+        b.extend_from_slice(&self.lo.to_be_bytes());
+        b
+    }
+
+    /// Creates a new PcgSource from a state returned by `state` or by
+    /// Go's `PCG.MarshalBinary`.
+    pub fn from_state(state: &[u8]) -> Result<PcgSource, RandError> {
+        if state.len() != 20 || !state.starts_with(b"pcg:") {
+            return Err(RandError::InvalidArgument("invalid PCG encoding"));
+        }
+        let mut hi = [0u8; 8];
+        let mut lo = [0u8; 8];
+        hi.copy_from_slice(&state[4..12]);
+        lo.copy_from_slice(&state[12..]);
+        Ok(PcgSource::new(
+            u64::from_be_bytes(hi),
+            u64::from_be_bytes(lo),
+        ))
+    }
+
+    fn next(&mut self) -> (u64, u64) {
+        const MUL: u128 = 2549297995355413924 << 64 | 4865540595714422341;
+        const INC: u128 = 6364136223846793005 << 64 | 1442695040888963407;
+        // state = state * mul + inc
+        let state = ((self.hi as u128) << 64 | self.lo as u128)
+            .wrapping_mul(MUL)
+            .wrapping_add(INC);
+        self.hi = (state >> 64) as u64;
+        self.lo = state as u64;
+        (self.hi, self.lo)
+    }
+}
+
+impl Source for PcgSource {
+    /// Expands seed into both state words with a `SeedExpander`.
+    fn seed(&mut self, seed: i64) {
+        let mut e = SeedExpander::new(seed as u64);
+        self.hi = e.next_u64();
+        self.lo = e.next_u64();
+    }
+
+    fn uint64(&mut self) -> u64 {
+        const CHEAP_MUL: u64 = 0xda942042e4dd58b5;
+        let (mut hi, lo) = self.next();
+        hi ^= hi >> 32;
+        hi = hi.wrapping_mul(CHEAP_MUL);
+        hi ^= hi >> (3 * 16);
+        hi.wrapping_mul(lo | 1)
+    }
+
+    fn int64(&mut self) -> i64 {
+        (self.uint64() & !(1 << 63)) as i64
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PcgSource {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_bytes(&self.state())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PcgSource {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<PcgSource, D::Error> {
+        let state = crate::serde_util::bytes(d)?;
+        PcgSource::from_state(&state).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PcgSource;
+    use crate::source::Source;
+    use crate::splitmix::SeedExpander;
+
+    #[test]
+    fn examples() {
+        println!("pcg source: uint64()");
+        let expected: Vec<u64> = vec![
+            0xc4f5a58656eef510,
+            0x9dcec3ad077dec6c,
+            0xc8d04605312f8088,
+            0xcbedc0dcb63ac19a,
+            0x3bf98798cae97950,
+            0xa8c6d7f8d485abc,
+            0x7ffa3780429cd279,
+            0x730ad2626b1c2f8e,
+            0x21ff2330f4a0ad99,
+            0x2f0901a1947094b0,
+            0xa9735a3cfbe36cef,
+            0x71ddb0a01a12c84a,
+            0xf0e53e77a78453bb,
+            0x1f173e9663be1e9d,
+            0x657651da3ac4115e,
+            0xc8987376b65a157b,
+            0xbb17008f5fca28e7,
+            0x8232bd645f29ed22,
+            0x12be8f07ad14c539,
+            0x54908a48e8e4736e,
+        ];
+        let mut p = PcgSource::new(1, 2);
+        let mut get = vec![];
+        for _i in 0..20 {
+            get.push(p.uint64());
+        }
+        assert_eq!(expected, get);
+
+        let mut p = PcgSource::new(1, 2);
+        p.uint64();
+        let mut q = p.clone();
+        for _i in 0..20 {
+            assert_eq!(p.uint64(), q.uint64());
+        }
+
+        let mut p = PcgSource::new(5, 6);
+        p.seed(2);
+        let mut e = SeedExpander::new(2);
+        let mut q = PcgSource::new(e.next_u64(), e.next_u64());
+        assert_eq!(q.uint64(), p.uint64());
+    }
+
+    #[test]
+    fn state() {
+        // Go's TestPCGMarshal.
+        let mut p = PcgSource::new(1, 2);
+        assert_eq!(
+            b"pcg:\x00\x00\x00\x00\x00\x00\x00\x01\x00\x00\x00\x00\x00\x00\x00\x02"[..],
+            p.state()[..]
+        );
+        p.uint64();
+        let mut q = PcgSource::from_state(&p.state()).unwrap();
+        for _i in 0..20 {
+            assert_eq!(p.uint64(), q.uint64());
+        }
+
+        assert!(PcgSource::from_state(b"pcg:").is_err());
+        assert!(PcgSource::from_state(&[b'x'; 20]).is_err());
+    }
+}
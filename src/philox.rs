@@ -0,0 +1,235 @@
+use crate::error::RandError;
+use crate::reader;
+use crate::source::Source;
+use crate::splitmix::SeedExpander;
+
+const PHILOX_M0: u32 = 0xd2511f53;
+const PHILOX_M1: u32 = 0xcd9e8d57;
+const PHILOX_W0: u32 = 0x9e3779b9;
+const PHILOX_W1: u32 = 0xbb67ae85;
+const ROUNDS: usize = 10;
+
+/// Returns the Philox4x32-10 block for key and counter, where the
+/// counter's least significant 32 bits form the first word of the
+/// block input.
+///
+/// Every block is computed independently of the others, so element
+/// i of a stream is available without generating the earlier ones.
+///
+/// See "Parallel Random Numbers: As Easy as 1, 2, 3"
+/// (Salmon, Moraes, Dror & Shaw, 2011)
+/// http://www.thesalmons.org/john/random123/papers/random123sc11.pdf
+///
+/// # Examples
+///
+/// ```no_run
+/// use srand::philox;
+///
+/// fn main() {
+///     let noise = philox([7, 0], 640 * 480 + 12);
+///     println!("noise: {:?}", noise);
+/// }
+/// ```
+pub fn philox(key: [u32; 2], counter: u128) -> [u32; 4] {
+    let mut key = key;
+    let mut ctr = [
+        counter as u32,
+        (counter >> 32) as u32,
+        (counter >> 64) as u32,
+        (counter >> 96) as u32,
+    ];
+    for round in 0..ROUNDS {
+        if round > 0 {
+            key[0] = key[0].wrapping_add(PHILOX_W0);
+            key[1] = key[1].wrapping_add(PHILOX_W1);
+        }
+        let p0 = PHILOX_M0 as u64 * ctr[0] as u64;
+        let p1 = PHILOX_M1 as u64 * ctr[2] as u64;
+        ctr = [
+            (p1 >> 32) as u32 ^ ctr[1] ^ key[0],
+            p1 as u32,
+            (p0 >> 32) as u32 ^ ctr[3] ^ key[1],
+            p0 as u32,
+        ];
+    }
+    ctr
+}
+
+/// A PhiloxSource is a counter-based generator producing the
+/// Philox4x32-10 blocks for counters 0, 1, 2, ... under a fixed key,
+/// one 32-bit word at a time.
+///
+/// set_counter moves it to any block of the stream in constant time.
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "PhiloxState")
+)]
+pub struct PhiloxSource {
+    key: [u32; 2],
+    counter: u128,
+    buf: [u32; 4],
+    idx: usize,
+}
+
+impl PhiloxSource {
+    /// Creates a new PhiloxSource with key, positioned at counter 0.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{PhiloxSource, Rand};
+    ///
+    /// fn main() {
+    ///     let mut src = PhiloxSource::new([7, 0]);
+    ///     src.set_counter(1 << 20);
+    ///     let mut r: Rand<_> = Rand::new(src);
+    ///     println!("n: {}", r.float64());
+    /// }
+    /// ```
+    pub fn new(key: [u32; 2]) -> PhiloxSource {
+        PhiloxSource {
+            key,
+            counter: 0,
+            buf: [0; 4],
+            idx: 4,
+        }
+    }
+
+    /// Creates a new PhiloxSource whose key is read from the system's
+    /// source of randomness, positioned at counter 0.
+    pub fn from_entropy() -> Result<PhiloxSource, RandError> {
+        let mut key = [0u64; 1];
+        reader::read_words(&mut key)?;
+        Ok(PhiloxSource::new([key[0] as u32, (key[0] >> 32) as u32]))
+    }
+
+    /// Moves the source to the start of the block for counter.
+    pub fn set_counter(&mut self, counter: u128) {
+        self.counter = counter;
+        self.idx = 4;
+    }
+
+    /// Returns the counter of the next block to be generated.
+    pub fn counter(&self) -> u128 {
+        self.counter
+    }
+}
+
+impl Source for PhiloxSource {
+    /// Expands seed into the key with a `SeedExpander` and moves the
+    /// source to counter 0.
+    fn seed(&mut self, seed: i64) {
+        let k = SeedExpander::new(seed as u64).next_u64();
+        self.key = [k as u32, (k >> 32) as u32];
+        self.set_counter(0);
+    }
+
+    fn uint32(&mut self) -> u32 {
+        if self.idx >= 4 {
+            self.buf = philox(self.key, self.counter);
+            self.counter = self.counter.wrapping_add(1);
+            self.idx = 0;
+        }
+        let x = self.buf[self.idx];
+        self.idx += 1;
+        x
+    }
+
+    fn uint64(&mut self) -> u64 {
+        let lo = self.uint32() as u64;
+        lo | (self.uint32() as u64) << 32
+    }
+
+    fn int64(&mut self) -> i64 {
+        (self.uint64() & !(1 << 63)) as i64
+    }
+}
+
+/// The serialized form of a PhiloxSource, checked before use.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct PhiloxState {
+    key: [u32; 2],
+    counter: u128,
+    buf: [u32; 4],
+    idx: usize,
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<PhiloxState> for PhiloxSource {
+    type Error = RandError;
+
+    fn try_from(s: PhiloxState) -> Result<PhiloxSource, RandError> {
+        if s.idx > 4 {
+            return Err(RandError::InvalidArgument("invalid PhiloxSource state"));
+        }
+        Ok(PhiloxSource {
+            key: s.key,
+            counter: s.counter,
+            buf: s.buf,
+            idx: s.idx,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{philox, PhiloxSource};
+    use crate::source::Source;
+    use crate::splitmix::SeedExpander;
+
+    fn counter(words: [u32; 4]) -> u128 {
+        words.iter().rev().fold(0, |acc, &w| acc << 32 | w as u128)
+    }
+
+    #[test]
+    fn known_answers() {
+        // kat_vectors of the Random123 distribution.
+        let vectors: Vec<([u32; 4], [u32; 2], [u32; 4])> = vec![
+            (
+                [0x00000000, 0x00000000, 0x00000000, 0x00000000],
+                [0x00000000, 0x00000000],
+                [0x6627e8d5, 0xe169c58d, 0xbc57ac4c, 0x9b00dbd8],
+            ),
+            (
+                [0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff],
+                [0xffffffff, 0xffffffff],
+                [0x408f276d, 0x41c83b0e, 0xa20bc7c6, 0x6d5451fd],
+            ),
+            (
+                [0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344],
+                [0xa4093822, 0x299f31d0],
+                [0xd16cfe09, 0x94fdcceb, 0x5001e420, 0x24126ea1],
+            ),
+        ];
+        for (ctr, key, expected) in vectors {
+            assert_eq!(expected, philox(key, counter(ctr)));
+        }
+    }
+
+    #[test]
+    fn random_access() {
+        let mut src = PhiloxSource::new([0xa4093822, 0x299f31d0]);
+        let mut get = vec![];
+        for _i in 0..12 {
+            get.push(src.uint32());
+        }
+        assert_eq!(3, src.counter());
+
+        src.set_counter(2);
+        for &x in &get[8..] {
+            assert_eq!(x, src.uint32());
+        }
+        src.set_counter(1);
+        assert_eq!(get[4] as u64 | (get[5] as u64) << 32, src.uint64());
+
+        let k = SeedExpander::new(3).next_u64();
+        let mut seeded = PhiloxSource::new([1, 2]);
+        seeded.uint32();
+        seeded.seed(3);
+        assert_eq!(0, seeded.counter());
+        assert_eq!(philox([k as u32, (k >> 32) as u32], 0)[0], seeded.uint32());
+    }
+}
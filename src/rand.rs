@@ -1,3 +1,4 @@
+use crate::error::RandError;
 pub use crate::source::Source;
 
 unsafe impl<S: Source> Send for Rand<S> where S: Send {}
@@ -21,6 +22,29 @@ where
     }
 }
 
+/// Reading from a Rand never fails and always fills the buffer, so a
+/// Rand is an endless stream; use `Read::take` to bound it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use srand::{Rand, RngSource};
+/// use std::io::Read;
+///
+/// fn main() -> std::io::Result<()> {
+///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+///     let mut out = vec![];
+///     std::io::copy(&mut r.by_ref().take(1 << 20), &mut out)?;
+///     Ok(())
+/// }
+/// ```
+impl<S: Source> std::io::Read for Rand<S> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.fill_bytes(buf);
+        Ok(buf.len())
+    }
+}
+
 impl<S> Rand<S>
 where
     S: Source,
@@ -83,6 +107,29 @@ where
         self.src.uint64()
     }
 
+    /// Fills buf with pseudo-random bytes from the source's
+    /// `fill_bytes`. Rand also implements `std::io::Read`.
+    ///
+    /// With an `RngSource` or `LockedSource` the bytes are those of Go's
+    /// `Rand.Read`: seven from every int64, lowest byte first, with the
+    /// unused bytes of the last one kept for the next call.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Rand, RngSource};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     let mut fixture = vec![0u8; 1024];
+    ///     r.fill_bytes(&mut fixture);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn fill_bytes(&mut self, buf: &mut [u8]) {
+        self.src.fill_bytes(buf)
+    }
+
     /// i32 returns a non-negative pseudo-random 31-bit integer as an i32.
     ///
     /// # Examples
@@ -114,7 +161,7 @@ where
     /// }
     /// ```
     pub fn uint32(&mut self) -> u32 {
-        (self.int64() >> 31) as u32
+        self.src.uint32()
     }
 
     /// Returns an i32, a non-negative pseudo-random number in [0,n).
@@ -147,6 +194,28 @@ where
         v % n
     }
 
+    /// Like int32n, but returns `RandError::InvalidArgument` instead of
+    /// panicking if n <= 0.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Rand, RngSource};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     println!("n: {}", r.try_int32n(90000001)?);
+    ///     assert!(r.try_int32n(0).is_err());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn try_int32n(&mut self, n: i32) -> Result<i32, RandError> {
+        if n <= 0 {
+            return Err(RandError::InvalidArgument("int32n: n must be > 0"));
+        }
+        Ok(self.int32n(n))
+    }
+
     /// Returns an i64, a non-negative pseudo-random number in [0,n).
     /// It panics if n <= 0.
     /// # Examples
@@ -177,6 +246,130 @@ where
         v % n
     }
 
+    /// Like int64n, but returns `RandError::InvalidArgument` instead of
+    /// panicking if n <= 0.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Rand, RngSource};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     println!("n: {}", r.try_int64n(90000001)?);
+    ///     assert!(r.try_int64n(0).is_err());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn try_int64n(&mut self, n: i64) -> Result<i64, RandError> {
+        if n <= 0 {
+            return Err(RandError::InvalidArgument("int64n: n must be > 0"));
+        }
+        Ok(self.int64n(n))
+    }
+
+    /// Returns a u32, a pseudo-random number in [0,n).
+    /// It panics if n == 0.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Rand, RngSource};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     println!("n: {}", r.uint32n(90000001));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn uint32n(&mut self, n: u32) -> u32 {
+        assert!(n > 0);
+        if n & (n - 1) == 0 {
+            // n is power of two, can mask
+            return self.uint64() as u32 & (n - 1);
+        }
+        // The probability of a random u64 lo being < a u32 n is near
+        // zero, meaning the unbiasing loop in uint64n almost never runs.
+        self.uint64n(n as u64) as u32
+    }
+
+    /// Like uint32n, but returns `RandError::InvalidArgument` instead of
+    /// panicking if n == 0.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Rand, RngSource};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     println!("n: {}", r.try_uint32n(90000001)?);
+    ///     assert!(r.try_uint32n(0).is_err());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn try_uint32n(&mut self, n: u32) -> Result<u32, RandError> {
+        if n == 0 {
+            return Err(RandError::InvalidArgument("uint32n: n must be > 0"));
+        }
+        Ok(self.uint32n(n))
+    }
+
+    /// Returns a u64, a pseudo-random number in [0,n).
+    /// It panics if n == 0.
+    ///
+    /// See Daniel Lemire, "Fast Random Integer Generation in an Interval"
+    /// https://arxiv.org/abs/1805.10941
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Rand, RngSource};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     println!("n: {}", r.uint64n(90000001));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn uint64n(&mut self, n: u64) -> u64 {
+        assert!(n > 0);
+        if n & (n - 1) == 0 {
+            // n is power of two, can mask
+            return self.uint64() & (n - 1);
+        }
+        let mut prod = self.uint64() as u128 * n as u128;
+        if (prod as u64) < n {
+            let thresh = n.wrapping_neg() % n;
+            while (prod as u64) < thresh {
+                prod = self.uint64() as u128 * n as u128;
+            }
+        }
+        (prod >> 64) as u64
+    }
+
+    /// Like uint64n, but returns `RandError::InvalidArgument` instead of
+    /// panicking if n == 0.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Rand, RngSource};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     println!("n: {}", r.try_uint64n(90000001)?);
+    ///     assert!(r.try_uint64n(0).is_err());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn try_uint64n(&mut self, n: u64) -> Result<u64, RandError> {
+        if n == 0 {
+            return Err(RandError::InvalidArgument("uint64n: n must be > 0"));
+        }
+        Ok(self.uint64n(n))
+    }
+
     /// Returns a f32, a pseudo-random number in [0.0,1.0).
     ///
     /// # Examples
@@ -214,13 +407,41 @@ where
     /// ```
     pub fn float64(&mut self) -> f64 {
         loop {
-            let f: f64 = self.int64() as f64 / (1 << 63) as f64;
+            let f: f64 = self.int64() as f64 / (1u64 << 63) as f64;
             if f != 1f64 {
                 return f;
             }
         }
     }
 
+    /// Returns, as a Vec of n usizes, a pseudo-random permutation
+    /// of the integers [0,n).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Rand, RngSource};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     println!("perm: {:?}", r.perm(10));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn perm(&mut self, n: usize) -> Vec<usize> {
+        let mut m = vec![0; n];
+        for i in 0..n {
+            let j = if i < i32::MAX as usize {
+                self.int32n((i + 1) as i32) as usize
+            } else {
+                self.int64n((i + 1) as i64) as usize
+            };
+            m[i] = m[j];
+            m[j] = i;
+        }
+        m
+    }
+
     /// Shuffle pseudo-randomizes the order of elements.
     ///
     /// # Examples
@@ -236,18 +457,86 @@ where
     ///     Ok(())
     /// }
     /// ```
-    pub fn shuffle<T>(&mut self, array: &mut Vec<T>) {
-        let mut i = array.len() - 1;
-        while i > 1 << 31 - 2 {
-            let j = self.int64n((i + 1) as i64);
-            array.swap(i as usize, j as usize);
+    pub fn shuffle<T>(&mut self, array: &mut [T]) {
+        self.shuffle_with(array.len(), |i, j| array.swap(i, j));
+    }
+
+    /// Pseudo-randomizes the order of n elements using the provided
+    /// swap function, which swaps the elements with indexes i and j.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Rand, RngSource};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     let mut keys = vec!["a", "b", "c", "d"];
+    ///     let mut values = vec![1, 2, 3, 4];
+    ///     r.shuffle_with(keys.len(), |i, j| {
+    ///         keys.swap(i, j);
+    ///         values.swap(i, j);
+    ///     });
+    ///     println!("keys: {:?}, values: {:?}", keys, values);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn shuffle_with<F>(&mut self, n: usize, mut swap: F)
+    where
+        F: FnMut(usize, usize),
+    {
+        let mut i = n;
+        while i > 1 {
             i -= 1;
+            let j = self.shuffle_index(i);
+            swap(i, j);
         }
-        while i > 0 {
-            let j = self.int32n((i + 1) as i32);
-            array.swap(i as usize, j as usize);
-            i -= 1;
+    }
+
+    /// Returns the index in [0,i] that element i is swapped with
+    /// during a shuffle.
+    ///
+    /// Shuffle really ought not be called with n that doesn't fit in
+    /// 32 bits. Not only will it take a very long time, but with 2³¹!
+    /// possible permutations, there's no way that any PRNG can have a
+    /// big enough internal state to generate even a minuscule
+    /// percentage of the possible permutations. Nevertheless, the
+    /// right API signature accepts a usize n, so handle it as best we
+    /// can, matching Go's Shuffle for every n that fits in an int32.
+    fn shuffle_index(&mut self, i: usize) -> usize {
+        if i as u64 > i64::MAX as u64 - 1 {
+            // i + 1 doesn't fit in an i64, but it never exceeds u64::MAX.
+            return self.uint64n(i as u64 + 1) as usize;
+        }
+        if i > (1 << 31) - 1 - 1 {
+            return self.int64n((i + 1) as i64) as usize;
         }
+        self.int31n((i + 1) as i32) as usize
+    }
+
+    /// Returns, as an i32, a non-negative pseudo-random number in [0,n).
+    /// n must be > 0, but int31n does not check this; the caller must
+    /// ensure it.
+    /// int31n exists because int32n is inefficient, but Go 1 compatibility
+    /// requires that the stream of values produced by Rand::int32n remain
+    /// unchanged. int31n can thus only be used internally, by newly
+    /// introduced APIs.
+    ///
+    /// See Daniel Lemire, "Fast Random Integer Generation in an Interval"
+    /// https://arxiv.org/abs/1805.10941
+    fn int31n(&mut self, n: i32) -> i32 {
+        let mut v = self.uint32();
+        let mut prod = v as u64 * n as u64;
+        let mut low = prod as u32;
+        if low < n as u32 {
+            let thresh = (n as u32).wrapping_neg() % n as u32;
+            while low < thresh {
+                v = self.uint32();
+                prod = v as u64 * n as u64;
+                low = prod as u32;
+            }
+        }
+        (prod >> 32) as i32
     }
 }
 
@@ -388,6 +677,136 @@ mod test {
             1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
         ];
         r.shuffle(&mut v);
-        println!("shuffle: {:?}", v);
+        assert_eq!(
+            vec![5, 15, 14, 10, 16, 9, 20, 19, 17, 6, 4, 2, 3, 1, 11, 7, 8, 12, 18, 13],
+            v
+        );
+
+        println!("custom rng source: perm(20)");
+        let expected: Vec<usize> = vec![
+            12, 4, 2, 13, 10, 0, 19, 11, 7, 5, 15, 18, 9, 14, 6, 8, 1, 16, 17, 3,
+        ];
+        let mut r: super::Rand<_> = super::Rand::new(RngSource::new(1));
+        assert_eq!(expected, r.perm(20));
+    }
+
+    #[test]
+    fn shuffle_bounds() {
+        let mut r: super::Rand<_> = super::Rand::new(RngSource::new(1));
+        let mut empty: Vec<i32> = vec![];
+        r.shuffle(&mut empty);
+        let mut single = vec![1];
+        r.shuffle(&mut single);
+        assert_eq!(vec![1], single);
+
+        for n in 0..10 {
+            let mut swaps = 0;
+            r.shuffle_with(n, |i, j| {
+                assert!(j <= i && i < n);
+                swaps += 1;
+            });
+            assert_eq!(n.saturating_sub(1), swaps);
+        }
+
+        let mut bounds = vec![0, 1, (1 << 31) - 2, (1 << 31) - 1];
+        if cfg!(target_pointer_width = "64") {
+            bounds.extend_from_slice(&[i64::MAX as usize - 1, i64::MAX as usize, usize::MAX - 1]);
+        }
+        for &i in &bounds {
+            for _ in 0..100 {
+                assert!(r.shuffle_index(i) <= i);
+            }
+        }
+    }
+
+    #[test]
+    fn unsigned_bounded() {
+        let expected: Vec<u64> = vec![
+            30, 47, 83, 71, 21, 34, 53, 57, 4, 65, 25, 40, 60, 19, 65, 23, 64, 64, 33, 60,
+        ];
+        let mut r: super::Rand<_> = super::Rand::new(RngSource::new(1));
+        let mut get = vec![];
+        for _i in 0..20 {
+            get.push(r.uint64n(100));
+        }
+        assert_eq!(expected, get);
+
+        // uint32n draws the same stream as uint64n for any n that fits.
+        let mut r: super::Rand<_> = super::Rand::new(RngSource::new(1));
+        let mut get = vec![];
+        for _i in 0..20 {
+            get.push(r.uint32n(100) as u64);
+        }
+        assert_eq!(expected, get);
+
+        for &n in &[1, 2, 3, 1 << 31, (1 << 31) + 1, u32::MAX - 1, u32::MAX] {
+            for _ in 0..100 {
+                assert!(r.uint32n(n) < n);
+            }
+        }
+        for &n in &[1, 2, 3, 1 << 63, (1 << 63) + 1, u64::MAX - 1, u64::MAX] {
+            for _ in 0..100 {
+                assert!(r.uint64n(n) < n);
+            }
+        }
+    }
+
+    #[test]
+    fn try_bounded() {
+        let mut r: super::Rand<_> = super::Rand::new(RngSource::new(1));
+        assert!(r.try_int32n(0).is_err());
+        assert!(r.try_int32n(-1).is_err());
+        assert!(r.try_int64n(0).is_err());
+        assert!(r.try_int64n(i64::MIN).is_err());
+        assert!(r.try_uint32n(0).is_err());
+        assert!(r.try_uint64n(0).is_err());
+
+        let mut r: super::Rand<_> = super::Rand::new(RngSource::new(1));
+        let mut s: super::Rand<_> = super::Rand::new(RngSource::new(1));
+        for _i in 0..50 {
+            assert_eq!(r.int32n(100), s.try_int32n(100).unwrap());
+            assert_eq!(r.int64n(100), s.try_int64n(100).unwrap());
+            assert_eq!(r.uint32n(100), s.try_uint32n(100).unwrap());
+            assert_eq!(r.uint64n(100), s.try_uint64n(100).unwrap());
+        }
+    }
+
+    #[test]
+    fn read() {
+        use std::io::Read;
+
+        // Seven bytes of each of Go's first two Int63 values for seed 1.
+        let expected: Vec<u8> = vec![82, 253, 252, 7, 33, 130, 101, 79, 22, 63, 95, 15, 154, 98];
+        let mut r: super::Rand<_> = super::Rand::new(RngSource::new(1));
+        let mut get = vec![0u8; 14];
+        assert_eq!(14, r.read(&mut get).unwrap());
+        assert_eq!(expected, get);
+
+        let mut r: super::Rand<_> = super::Rand::new(LockedSource::new(1));
+        let mut get = vec![];
+        std::io::copy(&mut r.by_ref().take(14), &mut get).unwrap();
+        assert_eq!(expected, get);
+
+        // Split reads continue where the last one ended.
+        for split in 0..=14 {
+            let mut r: super::Rand<_> = super::Rand::new(RngSource::new(1));
+            let mut get = vec![0u8; 14];
+            let (a, b) = get.split_at_mut(split);
+            r.read_exact(a).unwrap();
+            r.read_exact(b).unwrap();
+            assert_eq!(expected, get);
+        }
+        let mut r: super::Rand<_> = super::Rand::new(RngSource::new(1));
+        let mut get = vec![];
+        let mut reader = std::io::BufReader::with_capacity(3, r.by_ref().take(14));
+        std::io::copy(&mut reader, &mut get).unwrap();
+        assert_eq!(expected, get);
+
+        // Seeding drops the leftover bytes.
+        r.read_exact(&mut [0u8; 3]).unwrap();
+        r.seed(1);
+        let mut get = vec![0u8; 14];
+        r.read_exact(&mut get).unwrap();
+        assert_eq!(expected, get);
     }
 }
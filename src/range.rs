@@ -0,0 +1,288 @@
+use crate::error::RandError;
+use crate::rand::Rand;
+use crate::source::Source;
+use std::ops::{Range, RangeInclusive};
+
+/// A SampleRange is a range that values can be drawn from uniformly.
+///
+/// It is implemented for `Range` and `RangeInclusive` over every
+/// primitive integer type and over f32 and f64.
+pub trait SampleRange<T> {
+    /// Returns a value drawn uniformly from the range.
+    /// The range must not be empty.
+    fn sample<S: Source>(self, r: &mut Rand<S>) -> T;
+
+    /// Reports whether the range contains no values to draw.
+    fn is_empty(&self) -> bool;
+}
+
+impl<S> Rand<S>
+where
+    S: Source,
+{
+    /// Returns a value drawn uniformly from range, which may be a
+    /// half-open `lo..hi` or a closed `lo..=hi` range.
+    /// It panics if the range is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Rand, RngSource};
+    ///
+    /// fn main() -> std::io::Result<()> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     println!("n: {}", r.range(-10..10));
+    ///     println!("n: {}", r.range(i64::MIN..=i64::MAX));
+    ///     println!("n: {}", r.range(1.5..2.5));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn range<T, R>(&mut self, range: R) -> T
+    where
+        R: SampleRange<T>,
+    {
+        assert!(!range.is_empty());
+        range.sample(self)
+    }
+
+    /// Like range, but returns `RandError::InvalidArgument` instead of
+    /// panicking if the range is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Rand, RngSource};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     let n: i32 = r.try_range(-10..10)?;
+    ///     println!("n: {}", n);
+    ///     assert!(r.try_range(10..10).is_err());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn try_range<T, R>(&mut self, range: R) -> Result<T, RandError>
+    where
+        R: SampleRange<T>,
+    {
+        if range.is_empty() {
+            return Err(RandError::InvalidArgument("range: range must not be empty"));
+        }
+        Ok(range.sample(self))
+    }
+}
+
+/// Returns a u64, a pseudo-random number in [0,n). n must be > 0.
+/// Spans that fit in an i64 are drawn like `Rand::int64n`, so
+/// `range(0..n)` yields the same stream as `int64n(n)`.
+fn below64<S: Source>(r: &mut Rand<S>, n: u64) -> u64 {
+    if n <= i64::MAX as u64 {
+        return r.int64n(n as i64) as u64;
+    }
+    r.uint64n(n)
+}
+
+fn full64<S: Source>(r: &mut Rand<S>) -> u64 {
+    r.uint64()
+}
+
+/// Returns a u128, a pseudo-random number in [0,n). n must be > 0.
+fn below128<S: Source>(r: &mut Rand<S>, n: u128) -> u128 {
+    if n <= u64::MAX as u128 {
+        return r.uint64n(n as u64) as u128;
+    }
+    // Mask off the bits above n and reject the values beyond it,
+    // which happens less than half of the time.
+    let mask = u128::MAX >> (n - 1).leading_zeros();
+    loop {
+        let v = full128(r) & mask;
+        if v < n {
+            return v;
+        }
+    }
+}
+
+fn full128<S: Source>(r: &mut Rand<S>) -> u128 {
+    (r.uint64() as u128) << 64 | r.uint64() as u128
+}
+
+macro_rules! sample_range_int {
+    ($ty:ty, $unsigned:ty, $wide:ty, $below:ident, $full:ident) => {
+        impl SampleRange<$ty> for Range<$ty> {
+            fn sample<S: Source>(self, r: &mut Rand<S>) -> $ty {
+                let span = (self.end as $unsigned).wrapping_sub(self.start as $unsigned) as $wide;
+                let offset = $below(r, span);
+                self.start.wrapping_add(offset as $ty)
+            }
+
+            fn is_empty(&self) -> bool {
+                self.start >= self.end
+            }
+        }
+
+        impl SampleRange<$ty> for RangeInclusive<$ty> {
+            fn sample<S: Source>(self, r: &mut Rand<S>) -> $ty {
+                let (start, end) = self.into_inner();
+                let span = (end as $unsigned).wrapping_sub(start as $unsigned) as $wide;
+                let offset = match span.checked_add(1) {
+                    Some(n) => $below(r, n),
+                    // The range covers every value of the widest types.
+                    None => $full(r),
+                };
+                start.wrapping_add(offset as $ty)
+            }
+
+            fn is_empty(&self) -> bool {
+                self.start() > self.end()
+            }
+        }
+    };
+}
+
+sample_range_int!(i8, u8, u64, below64, full64);
+sample_range_int!(i16, u16, u64, below64, full64);
+sample_range_int!(i32, u32, u64, below64, full64);
+sample_range_int!(i64, u64, u64, below64, full64);
+sample_range_int!(isize, usize, u64, below64, full64);
+sample_range_int!(u8, u8, u64, below64, full64);
+sample_range_int!(u16, u16, u64, below64, full64);
+sample_range_int!(u32, u32, u64, below64, full64);
+sample_range_int!(u64, u64, u64, below64, full64);
+sample_range_int!(usize, usize, u64, below64, full64);
+sample_range_int!(i128, u128, u128, below128, full128);
+sample_range_int!(u128, u128, u128, below128, full128);
+
+macro_rules! sample_range_float {
+    ($ty:ty, $float:ident) => {
+        impl SampleRange<$ty> for Range<$ty> {
+            fn sample<S: Source>(self, r: &mut Rand<S>) -> $ty {
+                loop {
+                    let v = Lerp::lerp(self.start, self.end, r.$float());
+                    // Rounding may land on the excluded upper bound.
+                    if v < self.end {
+                        return v;
+                    }
+                }
+            }
+
+            fn is_empty(&self) -> bool {
+                !(self.start < self.end && self.start.is_finite() && self.end.is_finite())
+            }
+        }
+
+        impl SampleRange<$ty> for RangeInclusive<$ty> {
+            fn sample<S: Source>(self, r: &mut Rand<S>) -> $ty {
+                let (start, end) = self.into_inner();
+                Lerp::lerp(start, end, r.$float()).min(end)
+            }
+
+            fn is_empty(&self) -> bool {
+                !(self.start() <= self.end() && self.start().is_finite() && self.end().is_finite())
+            }
+        }
+
+        impl Lerp for $ty {
+            fn lerp(start: $ty, end: $ty, t: $ty) -> $ty {
+                let span = end - start;
+                if span.is_finite() {
+                    return start + span * t;
+                }
+                // The span overflows, as in MIN..MAX; work on halves.
+                2.0 * (start / 2.0 + (end / 2.0 - start / 2.0) * t)
+            }
+        }
+    };
+}
+
+/// Maps t in [0,1) onto [start, end) for finite start < end.
+trait Lerp {
+    fn lerp(start: Self, end: Self, t: Self) -> Self;
+}
+
+sample_range_float!(f32, float32);
+sample_range_float!(f64, float64);
+
+#[cfg(test)]
+mod test {
+    use crate::rand::Rand;
+    use crate::source::RngSource;
+
+    #[test]
+    fn examples() {
+        let expected: Vec<i32> = vec![
+            -40, 1, -29, 1, -13, -30, 8, -2, -34, -1, 34, 37, 24, -14, -35, 23, 18, 41, 40, -19,
+        ];
+        let mut r: Rand<_> = Rand::new(RngSource::new(1));
+        let mut get = vec![];
+        for _i in 0..20 {
+            get.push(r.range(-50..50));
+        }
+        assert_eq!(expected, get);
+
+        for _i in 0..1000 {
+            let v = r.range(-3i8..=3);
+            assert!((-3..=3).contains(&v));
+            let v = r.range(10u16..12);
+            assert!((10..12).contains(&v));
+            let v = r.range(i64::MIN..i64::MAX);
+            assert!(v < i64::MAX);
+            let v = r.range(u128::MAX - 5..=u128::MAX);
+            assert!(v >= u128::MAX - 5);
+            let v = r.range(i128::MIN..=i128::MIN + 1);
+            assert!(v <= i128::MIN + 1);
+            let v = r.range(-1.5f64..-0.5);
+            assert!((-1.5..-0.5).contains(&v));
+            let v = r.range(0.25f32..=0.5);
+            assert!((0.25..=0.5).contains(&v));
+        }
+        r.range(i64::MIN..=i64::MAX);
+        r.range(u128::MIN..=u128::MAX);
+        assert_eq!(7, r.range(7usize..=7));
+
+        let mut seen = [false; 256];
+        for _i in 0..10000 {
+            seen[r.range(i8::MIN..=i8::MAX) as u8 as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        for _i in 0..1000 {
+            let v = r.range(f64::MIN..f64::MAX);
+            assert!(v.is_finite() && v < f64::MAX);
+            let v = r.range(f32::MIN..=f32::MAX);
+            assert!(v.is_finite());
+        }
+    }
+
+    #[test]
+    fn int64n_compat() {
+        let mut r: Rand<_> = Rand::new(RngSource::new(1));
+        let mut s: Rand<_> = Rand::new(RngSource::new(1));
+        for n in [1i64, 2, 3, 7, 100, 1 << 40, i64::MAX] {
+            for _i in 0..100 {
+                assert_eq!(s.int64n(n), r.range(0..n));
+            }
+        }
+        for _i in 0..100 {
+            assert_eq!(s.int64n(100) - 50, r.range(-50i64..=49));
+        }
+    }
+
+    #[test]
+    fn try_empty() {
+        let mut r: Rand<_> = Rand::new(RngSource::new(1));
+        assert!(r.try_range(5..5).is_err());
+        let (lo, hi) = (5, 4);
+        assert!(r.try_range(lo..=hi).is_err());
+        assert!(r.try_range(1.0..f64::NAN).is_err());
+        assert!(r.try_range(f64::NEG_INFINITY..0.0).is_err());
+        assert!(r.try_range(0.0..=f64::INFINITY).is_err());
+        assert_eq!(5, r.try_range(5..=5).unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn empty() {
+        let mut r: Rand<_> = Rand::new(RngSource::new(1));
+        r.range(5..5);
+    }
+}
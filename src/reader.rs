@@ -1,16 +1,17 @@
+use crate::error::RandError;
 use std::fs::File;
-use std::io::Read;
-use std::sync::Mutex;
-
-lazy_static::lazy_static! {
-    /// Easy implementation: read from /dev/urandom.
-    /// This is sufficient on Linux, OS X, and FreeBSD.
-    static ref RANDOM_READER: Mutex<Reader<File>> = {
-        #[cfg(any(linux,unix,macos))]
-        let f : File = File::open("/dev/urandom").unwrap();
-        Mutex::new(Reader::new(f))
-    };
-}
+use std::io::{ErrorKind, Read};
+use std::sync::OnceLock;
+
+/// The largest number of bytes requested from the system at once;
+/// getrandom(2) never returns more than this in one call.
+const MAX_CHUNK: usize = (1 << 25) - 1;
+
+/// Fallback implementation: read from /dev/urandom.
+/// This is sufficient on Linux, OS X, and FreeBSD. The file is opened
+/// on first use, so a missing /dev is reported by read, and is shared
+/// by all threads without a lock since reads only need `&File`.
+static RANDOM_FILE: OnceLock<File> = OnceLock::new();
 
 pub struct Reader<R>
 where
@@ -26,24 +27,212 @@ impl<R: Read> Reader<R> {
 }
 
 impl<R: Read> Read for Reader<R> {
+    /// Reads at most buf.len() bytes and returns how many were read,
+    /// retrying reads interrupted by a signal.
     fn read(&mut self, buf: &mut [u8]) -> std::result::Result<usize, std::io::Error> {
-        self.r.read_exact(buf)?;
-        Ok(buf.len())
+        loop {
+            match self.r.read(buf) {
+                Err(ref err) if err.kind() == ErrorKind::Interrupted => continue,
+                result => return result,
+            }
+        }
     }
 }
 
-pub fn read(buf: &mut [u8]) -> std::result::Result<usize, std::io::Error> {
-    let mut r = RANDOM_READER.lock().unwrap();
-    r.read(buf)
+/// Fills buf with random bytes from the operating system.
+///
+/// On Linux it calls getrandom(2), which blocks only until the kernel's
+/// entropy pool has been initialised, and falls back to reading
+/// /dev/urandom on kernels without the syscall. Elsewhere it reads
+/// /dev/urandom.
+///
+/// The whole buffer is always filled: large requests are split into
+/// chunks, and reads interrupted by a signal are retried. No lock is
+/// taken, so it can be called from many threads at once.
+pub fn read(buf: &mut [u8]) -> std::result::Result<usize, RandError> {
+    for chunk in buf.chunks_mut(MAX_CHUNK) {
+        fill(chunk)?;
+    }
+    Ok(buf.len())
+}
+
+fn fill(buf: &mut [u8]) -> std::result::Result<(), RandError> {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        if getrandom::available() {
+            match getrandom::fill(buf) {
+                Ok(()) => return Ok(()),
+                Err(ref err) if err.raw_os_error() == Some(libc::ENOSYS) => getrandom::disable(),
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+    fill_urandom(buf)
+}
+
+fn fill_urandom(buf: &mut [u8]) -> std::result::Result<(), RandError> {
+    let f = match RANDOM_FILE.get() {
+        Some(f) => f,
+        None => {
+            let f = File::open("/dev/urandom")?;
+            // Another thread may have won the race; its file is kept.
+            RANDOM_FILE.get_or_init(|| f)
+        }
+    };
+    let mut r = Reader::new(f);
+    let mut filled = 0;
+    while filled < buf.len() {
+        match r.read(&mut buf[filled..])? {
+            0 => return Err(std::io::Error::from(ErrorKind::UnexpectedEof).into()),
+            n => filled += n,
+        }
+    }
+    Ok(())
+}
+
+/// Fills words with random values read from the system's source of
+/// randomness, decoding each word in little-endian byte order.
+pub(crate) fn read_words(words: &mut [u64]) -> std::result::Result<(), RandError> {
+    let mut buf = vec![0u8; words.len() * 8];
+    read(&mut buf)?;
+    for (word, chunk) in words.iter_mut().zip(buf.chunks(8)) {
+        let mut b = [0u8; 8];
+        b.copy_from_slice(chunk);
+        *word = u64::from_le_bytes(b);
+    }
+    Ok(())
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod getrandom {
+    use std::io::{Error, ErrorKind};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    static AVAILABLE: AtomicBool = AtomicBool::new(true);
+
+    /// Reports whether the getrandom syscall may exist on this kernel.
+    pub fn available() -> bool {
+        AVAILABLE.load(Ordering::Relaxed)
+    }
+
+    /// Records that the kernel lacks the getrandom syscall.
+    pub fn disable() {
+        AVAILABLE.store(false, Ordering::Relaxed);
+    }
+
+    /// Fills buf using getrandom(2) with no flags, which blocks until
+    /// the entropy pool is initialised and never afterwards.
+    pub fn fill(buf: &mut [u8]) -> Result<(), Error> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            let rest = &mut buf[filled..];
+            let n = unsafe {
+                libc::syscall(
+                    libc::SYS_getrandom,
+                    rest.as_mut_ptr() as *mut libc::c_void,
+                    rest.len(),
+                    0,
+                )
+            };
+            if n < 0 {
+                let err = Error::last_os_error();
+                if err.kind() == ErrorKind::Interrupted {
+                    continue;
+                }
+                return Err(err);
+            }
+            // Large requests may be satisfied partially.
+            filled += n as usize;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod test {
     #[test]
     fn random_read_examples() {
-        let mut buffer = Vec::with_capacity(16);
-        buffer.resize(16, 0u8);
+        let mut buffer = vec![0u8; 16];
         println!("{:?}", super::read(&mut buffer));
         println!("buffer: {:?}", buffer);
     }
+
+    #[test]
+    fn random_read_urandom() {
+        let mut a = vec![0u8; 64];
+        let mut b = vec![0u8; 64];
+        super::fill_urandom(&mut a).unwrap();
+        assert_eq!(64, super::read(&mut b).unwrap());
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn random_read_large() {
+        // Spans more than one chunk, so the tail must be filled too.
+        let mut buffer = vec![0u8; super::MAX_CHUNK + 4096];
+        assert_eq!(buffer.len(), super::read(&mut buffer).unwrap());
+        assert!(buffer[super::MAX_CHUNK..].iter().any(|&b| b != 0));
+    }
+
+    #[test]
+    fn reader_short_read() {
+        use std::io::Read;
+
+        let mut r = super::Reader::new(&[1u8, 2, 3][..]);
+        let mut buffer = [0u8; 8];
+        assert_eq!(3, r.read(&mut buffer).unwrap());
+        assert_eq!(0, r.read(&mut buffer).unwrap());
+    }
+
+    // Starts threads that each fill a 256-byte buffer 2000 times,
+    // checks that every read filled its buffer, and returns how long
+    // they took.
+    fn read_in_threads(threads: usize) -> std::time::Duration {
+        let start = std::time::Instant::now();
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                std::thread::spawn(|| {
+                    let mut buffer = [0u8; 256];
+                    let mut filled = 0;
+                    for _i in 0..2000 {
+                        buffer.fill(0);
+                        if super::read(&mut buffer).unwrap() == buffer.len()
+                            && buffer.iter().any(|&b| b != 0)
+                        {
+                            filled += 1;
+                        }
+                    }
+                    filled
+                })
+            })
+            .collect();
+        for h in handles {
+            assert_eq!(2000, h.join().unwrap());
+        }
+        start.elapsed()
+    }
+
+    #[test]
+    fn random_read_threads() {
+        read_in_threads(64);
+    }
+
+    // Timing depends on the machine and on whatever else runs, so this
+    // only runs on request: cargo test -- --ignored random_read_scaling
+    #[test]
+    #[ignore]
+    fn random_read_scaling() {
+        // Every thread does the same work, so without a shared lock the
+        // 64 threads take about 64/cores times as long as one thread,
+        // while a global lock would make it 64 times. Allow four times
+        // the ideal; with four cores or fewer the bound cannot tell.
+        let cores = std::thread::available_parallelism().map_or(1, |n| n.get().min(64));
+        let one = read_in_threads(1);
+        let many = read_in_threads(64);
+        println!(
+            "1 thread: {:?}, 64 threads: {:?}, {} cores",
+            one, many, cores
+        );
+        assert!(many * (cores as u32) < one * 64 * 4);
+    }
 }
@@ -0,0 +1,149 @@
+use crate::error::RandError;
+use crate::fork;
+use crate::source::Source;
+
+/// A ReseedingSource wraps a Source and replaces it with a freshly
+/// seeded one after it has produced a given number of bytes, and
+/// whenever the process has forked.
+///
+/// This makes it fork-safe: a child process forked from a parent that
+/// already used the source does not replay the parent's stream, but
+/// reseeds on its first call. The fork check is an atomic load on
+/// Linux and Android, so it adds no syscall to each call.
+///
+/// The Source methods cannot return errors, so they panic if reseeding
+/// fails.
+///
+/// # Examples
+///
+/// ```no_run
+/// use srand::{ChaCha20Source, Rand, ReseedingSource};
+///
+/// fn main() -> std::io::Result<()> {
+///     let src = ReseedingSource::new(ChaCha20Source::from_entropy, 1 << 20)?;
+///     let mut r: Rand<_> = Rand::new(src);
+///     println!("n: {}", r.uint64());
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ReseedingSource<S> {
+    inner: S,
+    reseed: fn() -> Result<S, RandError>,
+    threshold: u64,
+    remaining: u64,
+    fork: u64,
+}
+
+impl<S: Source> ReseedingSource<S> {
+    /// Creates a new ReseedingSource which calls reseed for its first
+    /// source and again after every threshold bytes of output. reseed
+    /// is usually a `from_entropy` function, such as
+    /// `ChaCha20Source::from_entropy`.
+    pub fn new(
+        reseed: fn() -> Result<S, RandError>,
+        threshold: u64,
+    ) -> Result<ReseedingSource<S>, RandError> {
+        Ok(ReseedingSource {
+            inner: reseed()?,
+            reseed,
+            threshold,
+            remaining: threshold,
+            fork: fork::generation(),
+        })
+    }
+
+    /// Replaces the wrapped source with a new one from reseed.
+    pub fn reseed(&mut self) -> Result<(), RandError> {
+        self.inner = (self.reseed)()?;
+        self.remaining = self.threshold;
+        self.fork = fork::generation();
+        Ok(())
+    }
+
+    /// Accounts for n bytes about to be produced, reseeding first if
+    /// the threshold was reached or the process has forked.
+    fn consume(&mut self, n: u64) -> &mut S {
+        if self.remaining < n || self.fork != fork::generation() {
+            self.reseed().expect("srand: failed to reseed source");
+        }
+        self.remaining -= n.min(self.remaining);
+        &mut self.inner
+    }
+}
+
+impl<S: Source> Source for ReseedingSource<S> {
+    /// Seeds the wrapped source. The output is only deterministic until
+    /// the next reseed.
+    fn seed(&mut self, seed: i64) {
+        self.inner.seed(seed);
+        self.remaining = self.threshold;
+    }
+
+    fn uint32(&mut self) -> u32 {
+        self.consume(4).uint32()
+    }
+
+    fn uint64(&mut self) -> u64 {
+        self.consume(8).uint64()
+    }
+
+    fn int64(&mut self) -> i64 {
+        self.consume(8).int64()
+    }
+
+    /// Fills buf at most threshold bytes at a time, so that a large
+    /// buffer still spans several reseeds.
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        let chunk = self.threshold.clamp(1, usize::MAX as u64) as usize;
+        for piece in buf.chunks_mut(chunk) {
+            self.consume(piece.len() as u64).fill_bytes(piece);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ReseedingSource;
+    use crate::error::RandError;
+    use crate::source::{RngSource, Source};
+    use std::sync::atomic::{AtomicI64, Ordering};
+
+    static SEEDS: AtomicI64 = AtomicI64::new(0);
+
+    fn counting() -> Result<RngSource, RandError> {
+        Ok(RngSource::new(SEEDS.fetch_add(1, Ordering::SeqCst) + 1))
+    }
+
+    #[test]
+    fn reseed_after_threshold() {
+        let mut src = ReseedingSource::new(counting, 16).unwrap();
+        let mut get = vec![];
+        for _i in 0..4 {
+            get.push(src.int64());
+        }
+        // Two values from each of two consecutive seeds.
+        let first = SEEDS.load(Ordering::SeqCst) - 1;
+        let mut a = RngSource::new(first);
+        let mut b = RngSource::new(first + 1);
+        let expected: Vec<i64> = vec![a.int64(), a.int64(), b.int64(), b.int64()];
+        assert_eq!(expected, get);
+
+        // A changed generation looks like a fork and forces a reseed.
+        let mut src = ReseedingSource::new(counting, 1 << 20).unwrap();
+        src.fork = src.fork.wrapping_add(1);
+        src.int64();
+        assert_eq!(crate::fork::generation(), src.fork);
+        assert_eq!((1 << 20) - 8, src.remaining);
+    }
+
+    #[test]
+    fn reseed_within_fill_bytes() {
+        let mut src = ReseedingSource::new(counting, 64).unwrap();
+        let before = SEEDS.load(Ordering::SeqCst);
+        let mut buf = vec![0u8; 2 * 64 + 1];
+        src.fill_bytes(&mut buf);
+        assert!(SEEDS.load(Ordering::SeqCst) - before >= 2);
+        assert_eq!(63, src.remaining);
+    }
+}
@@ -0,0 +1,148 @@
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use std::fmt;
+
+/// Serializes arrays longer than the 32 elements serde supports, as
+/// sequences. Use with `#[serde(with = "crate::serde_util::array")]`.
+pub(crate) mod array {
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::{Serialize, Serializer};
+    use std::convert::TryInto;
+
+    pub(crate) fn serialize<S, T, const N: usize>(a: &[T; N], s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        s.collect_seq(a.iter())
+    }
+
+    pub(crate) fn deserialize<'de, D, T, const N: usize>(d: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        let v = Vec::<T>::deserialize(d)?;
+        let len = v.len();
+        v.try_into().map_err(|_| {
+            D::Error::invalid_length(len, &format!("an array of length {}", N).as_str())
+        })
+    }
+}
+
+/// Deserializes an encoded state written with `serialize_bytes`,
+/// accepting formats that turn bytes into a sequence of u8s.
+pub(crate) fn bytes<'de, D>(d: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a generator state")
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Vec<u8>, E> {
+            Ok(v)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+            let mut v = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(b) = seq.next_element()? {
+                v.push(b);
+            }
+            Ok(v)
+        }
+    }
+
+    d.deserialize_bytes(BytesVisitor)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::chacha20::ChaCha20Source;
+    use crate::chacha8::ChaCha8Source;
+    use crate::mt19937::{Mt19937Source, Mt19937_64Source};
+    use crate::pcg::PcgSource;
+    use crate::philox::PhiloxSource;
+    use crate::source::{LockedSource, RngSource, Source};
+    use crate::splitmix::SplitMix64;
+    use crate::xoshiro::{Xoroshiro128Plus, Xoshiro256StarStar};
+
+    fn round_trip<S>(mut src: S)
+    where
+        S: Source + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        for _i in 0..1000 {
+            src.uint32();
+        }
+        let json = serde_json::to_string(&src).unwrap();
+        let mut resumed: S = serde_json::from_str(&json).unwrap();
+        for _i in 0..1000 {
+            assert_eq!(src.uint64(), resumed.uint64());
+        }
+    }
+
+    #[test]
+    fn sources() {
+        round_trip(RngSource::new(1));
+        round_trip(LockedSource::new(1));
+        round_trip(PcgSource::new(1, 2));
+        round_trip(ChaCha8Source::new(*b"ABCDEFGHIJKLMNOPQRSTUVWXYZ123456"));
+        round_trip(ChaCha20Source::new([7; 32]));
+        round_trip(Mt19937Source::new(5489));
+        round_trip(Mt19937_64Source::new(5489));
+        round_trip(PhiloxSource::new([7, 0]));
+        round_trip(SplitMix64::new(1));
+        round_trip(Xoshiro256StarStar::new(1));
+        round_trip(Xoroshiro128Plus::new(1));
+
+        assert!(serde_json::from_str::<RngSource>("[1, 2, 3]").is_err());
+        assert!(serde_json::from_str::<PcgSource>("[1, 2, 3]").is_err());
+        let p = PcgSource::new(1, 2);
+        let bytes: Vec<u8> = serde_json::from_str(&serde_json::to_string(&p).unwrap()).unwrap();
+        assert_eq!(p.state(), bytes);
+        assert!(serde_json::from_str::<Mt19937Source>(r#"{"mt": [1], "mti": 0}"#).is_err());
+    }
+
+    /// Serializes src, sets field to value and reports whether the
+    /// result is rejected.
+    fn rejects<S>(src: S, field: &str, value: serde_json::Value) -> bool
+    where
+        S: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let mut json = serde_json::to_value(&src).unwrap();
+        json[field] = value;
+        serde_json::from_value::<S>(json).is_err()
+    }
+
+    #[test]
+    fn invalid_states() {
+        use serde_json::json;
+
+        let chacha8 = || ChaCha8Source::new([7; 32]);
+        assert!(rejects(chacha8(), "i", json!(40)));
+        assert!(rejects(chacha8(), "n", json!(100)));
+        assert!(rejects(chacha8(), "c", json!(2)));
+        assert!(rejects(chacha8(), "c", json!(16)));
+        assert!(!rejects(chacha8(), "i", json!(32)));
+        assert!(rejects(ChaCha20Source::new([7; 32]), "i", json!(17)));
+        assert!(rejects(ChaCha20Source::new([7; 32]), "read_pos", json!(5)));
+        assert!(rejects(PhiloxSource::new([7, 0]), "idx", json!(5)));
+        assert!(rejects(
+            Xoshiro256StarStar::new(1),
+            "s",
+            json!([0, 0, 0, 0])
+        ));
+        assert!(rejects(Xoroshiro128Plus::new(1), "s", json!([0, 0])));
+        assert!(rejects(Mt19937Source::new(5489), "mti", json!(625)));
+        assert!(rejects(Mt19937Source::new(5489), "mt", json!(vec![0; 624])));
+        assert!(rejects(Mt19937_64Source::new(5489), "mti", json!(313)));
+    }
+}
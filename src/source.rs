@@ -1,3 +1,6 @@
+use crate::error::RandError;
+use crate::reader;
+use crate::splitmix::SeedExpander;
 use std::sync::{Arc, Mutex};
 
 /// A Source represents a source of uniformly-distributed
@@ -9,6 +12,29 @@ pub trait Source {
     /// Seed uses the provided seed value to initialize the generator
     /// to a deterministic state.
     fn seed(&mut self, seed: i64);
+    /// Returns a pseudo-random 32-bit value as a u32.
+    /// Sources that natively produce 32-bit values can override it
+    /// to return them unchanged.
+    fn uint32(&mut self) -> u32 {
+        (self.int64() >> 31) as u32
+    }
+    /// Fills buf with pseudo-random bytes.
+    ///
+    /// The default takes seven bytes from every int64, lowest byte
+    /// first, and drops what is left of the last one, so its output
+    /// depends on how a stream is split into calls. `RngSource` and
+    /// `LockedSource` keep those bytes for the next call instead, like
+    /// Go's `Rand.Read`. Sources can override it with something faster
+    /// that carries any leftover across calls.
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(7) {
+            let mut val = self.int64();
+            for b in chunk.iter_mut() {
+                *b = val as u8;
+                val >>= 8;
+            }
+        }
+    }
 }
 
 const RNG_LEN: usize = 607;
@@ -16,6 +42,8 @@ const RNG_TAP: i64 = 273;
 const RNG_MAX: u64 = 1 << 63;
 const RNG_MASK: u64 = RNG_MAX - 1;
 const INT_32_MAX: i64 = (1 << 31) - 1;
+const RNG_STATE_PREFIX: &[u8] = b"rng:";
+const RNG_STATE_LEN: usize = 4 + 8 * (3 + RNG_LEN) + 1;
 
 const RNG_COOKED: [i64; RNG_LEN] = [
     -4181792142133755926,
@@ -631,6 +659,10 @@ pub struct RngSource {
     tap: i64,
     feed: i64,
     vector: [i64; RNG_LEN],
+    // The int64 that fill_bytes is taking bytes from, and how many of
+    // its bytes are left, so that reads continue where the last ended.
+    read_val: i64,
+    read_pos: u8,
 }
 
 fn seedrand(x: i64) -> i64 {
@@ -664,20 +696,138 @@ impl RngSource {
             tap: 0,
             feed: 0,
             vector: [0; RNG_LEN],
+            read_val: 0,
+            read_pos: 0,
         };
         src.seed(seed);
         src
     }
+
+    /// Creates a new RngSource seeded from the system's source of
+    /// randomness.
+    ///
+    /// The whole feedback register is read from it, so unlike `new`,
+    /// whose seed is reduced modulo 2^31-1, it is not limited to 2^31
+    /// streams.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::RngSource;
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let src = RngSource::from_entropy()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_entropy() -> Result<RngSource, RandError> {
+        let mut words = [0u64; RNG_LEN];
+        reader::read_words(&mut words)?;
+        Ok(RngSource::from_words(&words))
+    }
+
+    /// Creates a new RngSource whose feedback register is expanded
+    /// from seed with a `SeedExpander`, so every u64 seed gives its
+    /// own stream.
+    pub(crate) fn from_expanded_seed(seed: u64) -> RngSource {
+        let mut words = [0u64; RNG_LEN];
+        SeedExpander::new(seed).fill(&mut words);
+        RngSource::from_words(&words)
+    }
+
+    fn from_words(words: &[u64; RNG_LEN]) -> RngSource {
+        let mut src = RngSource {
+            tap: 0,
+            feed: RNG_LEN as i64 - RNG_TAP,
+            vector: [0; RNG_LEN],
+            read_val: 0,
+            read_pos: 0,
+        };
+        for (x, w) in src.vector.iter_mut().zip(words.iter()) {
+            *x = *w as i64;
+        }
+        src
+    }
+
+    /// Returns the generator's state, from which `from_state` resumes
+    /// the same stream.
+    ///
+    /// Go's rngSource cannot be marshaled, so the encoding follows the
+    /// one of Go's PCG: "rng:", then tap, feed, the 607 words of the
+    /// feedback register and the int64 `fill_bytes` is taking bytes
+    /// from, each as a big-endian u64, and last the number of its bytes
+    /// left.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{RngSource, Source};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut src = RngSource::new(1);
+    ///     let state = src.state();
+    ///     let mut resumed = RngSource::from_state(&state)?;
+    ///     assert_eq!(src.int64(), resumed.int64());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn state(&self) -> Vec<u8> {
+        let mut b = Vec::with_capacity(RNG_STATE_LEN);
+        b.extend_from_slice(RNG_STATE_PREFIX);
+        b.extend_from_slice(&(self.tap as u64).to_be_bytes());
+        b.extend_from_slice(&(self.feed as u64).to_be_bytes());
+        for x in self.vector.iter() {
+            b.extend_from_slice(&(*x as u64).to_be_bytes());
+        }
+        b.extend_from_slice(&(self.read_val as u64).to_be_bytes());
+        b.push(self.read_pos);
+        b
+    }
+
+    /// Creates a new RngSource from a state returned by `state`.
+    pub fn from_state(state: &[u8]) -> Result<RngSource, RandError> {
+        if state.len() != RNG_STATE_LEN || !state.starts_with(RNG_STATE_PREFIX) {
+            return Err(RandError::InvalidArgument("invalid RngSource encoding"));
+        }
+        let read_pos = state[RNG_STATE_LEN - 1];
+        let mut words = state[RNG_STATE_PREFIX.len()..RNG_STATE_LEN - 1]
+            .chunks(8)
+            .map(|c| {
+                let mut b = [0u8; 8];
+                b.copy_from_slice(c);
+                u64::from_be_bytes(b) as i64
+            });
+        let tap = words.next().unwrap();
+        let feed = words.next().unwrap();
+        if !(0..RNG_LEN as i64).contains(&tap)
+            || !(0..RNG_LEN as i64).contains(&feed)
+            || read_pos > 7
+        {
+            return Err(RandError::InvalidArgument("invalid RngSource encoding"));
+        }
+        let mut vector = [0; RNG_LEN];
+        for x in vector.iter_mut() {
+            *x = words.next().unwrap();
+        }
+        Ok(RngSource {
+            tap,
+            feed,
+            vector,
+            read_val: words.next().unwrap(),
+            read_pos,
+        })
+    }
 }
 
 impl Source for RngSource {
     fn seed(&mut self, seed: i64) {
         self.tap = 0;
         self.feed = RNG_LEN as i64 - RNG_TAP;
+        self.read_pos = 0;
         let seed = seed % INT_32_MAX;
         let seed = if seed < 0 { seed + INT_32_MAX } else { seed };
         let seed = if seed == 0 { 89482311 } else { seed };
-        let mut x = seed as i64;
+        let mut x = seed;
         let mut i: i64 = -20;
         while i < RNG_LEN as i64 {
             x = seedrand(x);
@@ -712,6 +862,26 @@ impl Source for RngSource {
     fn int64(&mut self) -> i64 {
         (self.uint64() & RNG_MASK) as i64
     }
+
+    /// Takes seven bytes from every int64, lowest byte first, like Go's
+    /// `Rand.Read`, and keeps the unused bytes of the last one for the
+    /// next call, so the bytes produced do not depend on how reads are
+    /// split.
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        let mut pos = self.read_pos;
+        let mut val = self.read_val;
+        for b in buf.iter_mut() {
+            if pos == 0 {
+                val = self.int64();
+                pos = 7;
+            }
+            *b = val as u8;
+            val >>= 8;
+            pos -= 1;
+        }
+        self.read_pos = pos;
+        self.read_val = val;
+    }
 }
 
 #[derive(Clone)]
@@ -728,6 +898,30 @@ impl LockedSource {
             inner: Arc::new(Mutex::new(RngSource::new(seed))),
         }
     }
+
+    /// Creates a new LockedSource seeded from the system's source of
+    /// randomness.
+    pub fn from_entropy() -> Result<LockedSource, RandError> {
+        Ok(LockedSource {
+            inner: Arc::new(Mutex::new(RngSource::from_entropy()?)),
+        })
+    }
+
+    /// Returns the state of the shared generator in the encoding of
+    /// `RngSource::state`. The state is taken under the lock, so no
+    /// other clone can advance the generator halfway through.
+    pub fn state(&self) -> Vec<u8> {
+        let src = self.inner.lock().unwrap();
+        src.state()
+    }
+
+    /// Creates a new LockedSource from a state returned by `state` or
+    /// `RngSource::state`.
+    pub fn from_state(state: &[u8]) -> Result<LockedSource, RandError> {
+        Ok(LockedSource {
+            inner: Arc::new(Mutex::new(RngSource::from_state(state)?)),
+        })
+    }
 }
 
 impl Source for LockedSource {
@@ -743,6 +937,42 @@ impl Source for LockedSource {
         let mut src = self.inner.lock().unwrap();
         src.uint64()
     }
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        let mut src = self.inner.lock().unwrap();
+        src.fill_bytes(buf)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RngSource {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_bytes(&self.state())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RngSource {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<RngSource, D::Error> {
+        let state = crate::serde_util::bytes(d)?;
+        RngSource::from_state(&state).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serializes a snapshot of the shared generator, see
+/// `LockedSource::state`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for LockedSource {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_bytes(&self.state())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for LockedSource {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<LockedSource, D::Error> {
+        let state = crate::serde_util::bytes(d)?;
+        LockedSource::from_state(&state).map_err(serde::de::Error::custom)
+    }
 }
 
 #[cfg(test)]
@@ -755,6 +985,70 @@ mod test {
         assert_eq!(a, 3);
     }
 
+    #[test]
+    fn from_entropy() {
+        use super::Source;
+        let draw = |src: &mut dyn Source| -> Vec<i64> { (0..4).map(|_| src.int64()).collect() };
+        let mut a = super::RngSource::from_entropy().unwrap();
+        let mut b = super::RngSource::from_entropy().unwrap();
+        assert_ne!(draw(&mut a), draw(&mut b));
+        let mut a = super::LockedSource::from_entropy().unwrap();
+        let mut b = super::LockedSource::from_entropy().unwrap();
+        assert_ne!(draw(&mut a), draw(&mut b));
+    }
+
+    #[test]
+    fn expanded_seed() {
+        use super::{RngSource, Source, INT_32_MAX};
+        // new reduces its seed modulo 2^31-1; the expansion does not.
+        let s = 12345;
+        let draw = |mut src: RngSource| -> Vec<i64> { (0..4).map(|_| src.int64()).collect() };
+        assert_eq!(
+            draw(RngSource::new(s)),
+            draw(RngSource::new(s + INT_32_MAX))
+        );
+        assert_ne!(
+            draw(RngSource::from_expanded_seed(s as u64)),
+            draw(RngSource::from_expanded_seed((s + INT_32_MAX) as u64))
+        );
+    }
+
+    #[test]
+    fn state() {
+        use super::{LockedSource, RngSource, Source};
+        let mut a = RngSource::new(1);
+        for _i in 0..1000 {
+            a.int64();
+        }
+        let mut buf = [0u8; 3];
+        a.fill_bytes(&mut buf);
+        let state = a.state();
+        assert_eq!(4885, state.len());
+        let mut b = RngSource::from_state(&state).unwrap();
+        let mut c = LockedSource::from_state(&state).unwrap();
+        // The leftover bytes of the last read carry over.
+        let mut get = [0u8; 4];
+        a.fill_bytes(&mut buf);
+        b.fill_bytes(&mut get[..3]);
+        assert_eq!(buf, get[..3]);
+        c.fill_bytes(&mut get[..3]);
+        assert_eq!(buf, get[..3]);
+        for _i in 0..1000 {
+            let x = a.uint64();
+            assert_eq!(x, b.uint64());
+            assert_eq!(x, c.uint64());
+        }
+        assert_eq!(a.state(), c.clone().state());
+
+        assert!(RngSource::from_state(&state[1..]).is_err());
+        let mut bad = state.clone();
+        bad[4..12].copy_from_slice(&607u64.to_be_bytes());
+        assert!(RngSource::from_state(&bad).is_err());
+        let mut bad = state.clone();
+        bad[4884] = 8;
+        assert!(RngSource::from_state(&bad).is_err());
+    }
+
     #[test]
     fn print_const() {
         println!("RNG_MAX: {}", super::RNG_MAX);
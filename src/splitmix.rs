@@ -0,0 +1,153 @@
+use crate::error::RandError;
+use crate::reader;
+use crate::source::Source;
+
+/// A SplitMix64 is the SplitMix64 generator with 64 bits of internal
+/// state. Every u64 seed yields a distinct stream.
+///
+/// It is fast and passes BigCrush, but is mostly used to expand a
+/// single seed into the larger state of other generators; see
+/// `SeedExpander`.
+///
+/// See https://prng.di.unimi.it/splitmix64.c
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Creates a new SplitMix64 seeded with seed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::SplitMix64;
+    ///
+    /// fn main() {
+    ///     let src = SplitMix64::new(1);
+    /// }
+    /// ```
+    pub fn new(seed: u64) -> SplitMix64 {
+        SplitMix64 { state: seed }
+    }
+
+    /// Creates a new SplitMix64 seeded from the system's source of
+    /// randomness.
+    pub fn from_entropy() -> Result<SplitMix64, RandError> {
+        let mut seed = [0u64; 1];
+        reader::read_words(&mut seed)?;
+        Ok(SplitMix64::new(seed[0]))
+    }
+}
+
+impl Source for SplitMix64 {
+    fn seed(&mut self, seed: i64) {
+        self.state = seed as u64;
+    }
+
+    fn uint64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn int64(&mut self) -> i64 {
+        (self.uint64() & !(1 << 63)) as i64
+    }
+}
+
+/// A SeedExpander derives the full-width state of a generator from a
+/// single u64 seed, by drawing consecutive outputs of a SplitMix64.
+///
+/// Unlike `RngSource::seed`, which reduces its seed modulo 2^31-1,
+/// every u64 seed gives a different expansion.
+///
+/// # Examples
+///
+/// ```no_run
+/// use srand::{SeedExpander, Xoshiro256StarStar};
+///
+/// fn main() -> Result<(), srand::RandError> {
+///     let mut state = [0u64; 4];
+///     SeedExpander::new(1).fill(&mut state);
+///     let src = Xoshiro256StarStar::from_state(state)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeedExpander {
+    sm: SplitMix64,
+}
+
+impl SeedExpander {
+    /// Creates a new SeedExpander for seed.
+    pub fn new(seed: u64) -> SeedExpander {
+        SeedExpander {
+            sm: SplitMix64::new(seed),
+        }
+    }
+
+    /// Returns the next 64 bits of the expansion.
+    pub fn next_u64(&mut self) -> u64 {
+        self.sm.uint64()
+    }
+
+    /// Fills words with the next words of the expansion.
+    pub fn fill(&mut self, words: &mut [u64]) {
+        for word in words.iter_mut() {
+            *word = self.next_u64();
+        }
+    }
+
+    /// Fills bytes with the next words of the expansion, each in
+    /// little-endian byte order.
+    pub fn fill_bytes(&mut self, bytes: &mut [u8]) {
+        for chunk in bytes.chunks_mut(8) {
+            let word = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&word[..chunk.len()]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{SeedExpander, SplitMix64};
+    use crate::source::{RngSource, Source};
+
+    #[test]
+    fn examples() {
+        let expected: Vec<u64> = vec![
+            6457827717110365317,
+            3203168211198807973,
+            9817491932198370423,
+            4593380528125082431,
+            16408922859458223821,
+        ];
+        let mut src = SplitMix64::new(1234567);
+        let mut get = vec![];
+        for _i in 0..5 {
+            get.push(src.uint64());
+        }
+        assert_eq!(expected, get);
+
+        // Seeds that differ by a multiple of 2^31-1 collide in RngSource,
+        // but not in SplitMix64.
+        let (a, b) = (1, 1 + ((1 << 31) - 1));
+        assert_eq!(RngSource::new(a).uint64(), RngSource::new(b).uint64());
+        assert_ne!(
+            SplitMix64::new(a as u64).uint64(),
+            SplitMix64::new(b as u64).uint64()
+        );
+
+        let mut words = [0u64; 2];
+        let mut bytes = [0u8; 12];
+        SeedExpander::new(1).fill(&mut words);
+        SeedExpander::new(1).fill_bytes(&mut bytes);
+        assert_eq!(words[0].to_le_bytes(), bytes[..8]);
+        assert_eq!(words[1].to_le_bytes()[..4], bytes[8..]);
+    }
+}
@@ -1,17 +1,169 @@
+use crate::chacha20::ChaCha20Source;
+use crate::error::RandError;
 use crate::rand::Rand;
-use crate::source::RngSource;
+use crate::range::SampleRange;
+use crate::reseeding::ReseedingSource;
+use crate::source::{RngSource, Source};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Bytes a reseeding thread generator produces between reseeds.
+const RESEED_THRESHOLD: u64 = 1 << 20;
+
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+static RESEEDING: AtomicBool = AtomicBool::new(false);
 
 thread_local!(
-    pub static THREAD_RAND: std::cell::RefCell<Rand<RngSource>> =
-        std::cell::RefCell::new(Rand::new(RngSource::new(1)));
+    pub static THREAD_RAND: std::cell::RefCell<Rand<ThreadSource>> =
+        std::cell::RefCell::new(Rand::new(new_source()));
 );
 
+/// The source behind a thread's generator, chosen when the thread
+/// first uses it.
+// There is one per thread, so the RngSource is not worth boxing.
+#[allow(clippy::large_enum_variant)]
+pub enum ThreadSource {
+    Rng(RngSource),
+    Reseeding(ReseedingSource<ChaCha20Source>),
+}
+
+impl Source for ThreadSource {
+    fn seed(&mut self, seed: i64) {
+        match self {
+            ThreadSource::Rng(src) => src.seed(seed),
+            ThreadSource::Reseeding(src) => src.seed(seed),
+        }
+    }
+
+    fn uint32(&mut self) -> u32 {
+        match self {
+            ThreadSource::Rng(src) => src.uint32(),
+            ThreadSource::Reseeding(src) => src.uint32(),
+        }
+    }
+
+    fn uint64(&mut self) -> u64 {
+        match self {
+            ThreadSource::Rng(src) => src.uint64(),
+            ThreadSource::Reseeding(src) => src.uint64(),
+        }
+    }
+
+    fn int64(&mut self) -> i64 {
+        match self {
+            ThreadSource::Rng(src) => src.int64(),
+            ThreadSource::Reseeding(src) => src.int64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        match self {
+            ThreadSource::Rng(src) => src.fill_bytes(buf),
+            ThreadSource::Reseeding(src) => src.fill_bytes(buf),
+        }
+    }
+}
+
+/// Returns the source a thread's generator starts from: seeded with
+/// 1 in deterministic mode, a reseeding ChaCha20 in reseeding mode, and
+/// an RngSource seeded from the system's source of randomness
+/// otherwise.
+///
+/// In reseeding mode it panics if the system's source of randomness
+/// cannot be read, rather than giving up the fork safety asked for.
+fn new_source() -> ThreadSource {
+    if DETERMINISTIC.load(Ordering::SeqCst) {
+        return ThreadSource::Rng(RngSource::new(1));
+    }
+    if RESEEDING.load(Ordering::SeqCst) {
+        let src = ReseedingSource::new(ChaCha20Source::from_entropy, RESEED_THRESHOLD)
+            .expect("srand: failed to seed reseeding thread generator");
+        return ThreadSource::Reseeding(src);
+    }
+    ThreadSource::Rng(RngSource::from_entropy().unwrap_or_else(|_| {
+        // There is no entropy to be had; the clock still keeps threads
+        // and processes from sharing a stream.
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as i64)
+            .unwrap_or(0);
+        let local = 0u8;
+        RngSource::from_expanded_seed((now ^ (&local as *const u8 as i64)) as u64)
+    }))
+}
+
+/// ThreadLocal gives every thread its own generator, seeded lazily
+/// from the system's source of randomness on first use, so threads
+/// and processes do not share a stream.
+///
+/// Call `ThreadLocal::seed` to make the calling thread deterministic,
+/// or `ThreadLocal::set_deterministic` to restore the behaviour of
+/// starting every thread from seed 1. Processes that fork should call
+/// `ThreadLocal::set_reseeding`, since a forked child otherwise
+/// continues its parent's stream.
+///
+/// If the system's source of randomness cannot be read, a thread's
+/// generator is seeded from the clock and a stack address instead.
+/// That still keeps threads apart but is guessable, so code that needs
+/// unpredictable values should create a source with `from_entropy`
+/// and handle its error, or use `CryptoSource`.
 pub struct ThreadLocal;
 
 impl ThreadLocal {
+    /// Sets whether generators start from seed 1 instead of from the
+    /// system's source of randomness. Enabling it also reseeds the
+    /// calling thread's generator with 1; other threads that already
+    /// used their generator keep their stream.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::ThreadLocal;
+    ///
+    /// fn main() {
+    ///     ThreadLocal::set_deterministic(true);
+    ///     assert_eq!(5577006791947779410, ThreadLocal::int64());
+    /// }
+    /// ```
+    pub fn set_deterministic(enabled: bool) {
+        DETERMINISTIC.store(enabled, Ordering::SeqCst);
+        if enabled {
+            ThreadLocal::seed(1);
+        }
+    }
+
+    /// Sets whether generators are a ChaCha20 source that reseeds from
+    /// the system's source of randomness every megabyte of output and
+    /// after the process forks, see `ReseedingSource`. The calling
+    /// thread's generator is replaced at once; other threads that
+    /// already used their generator keep their source. Deterministic
+    /// mode takes precedence.
+    ///
+    /// While it is enabled, creating a thread's generator panics if the
+    /// system's source of randomness cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::ThreadLocal;
+    ///
+    /// fn main() {
+    ///     ThreadLocal::set_reseeding(true);
+    ///     println!("n: {}", ThreadLocal::int64());
+    /// }
+    /// ```
+    pub fn set_reseeding(enabled: bool) {
+        RESEEDING.store(enabled, Ordering::SeqCst);
+        THREAD_RAND.with(|x| {
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
+            let mut x = x.borrow_mut();
+            *x = Rand::new(new_source());
+        })
+    }
+
     pub fn seed(seed: i64) {
         THREAD_RAND.with(|x| {
-            let x: &std::cell::RefCell<Rand<RngSource>> = x;
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
             let mut x = x.borrow_mut();
             x.seed(seed);
         })
@@ -19,7 +171,7 @@ impl ThreadLocal {
 
     pub fn int32() -> i32 {
         THREAD_RAND.with(|x| {
-            let x: &std::cell::RefCell<Rand<RngSource>> = x;
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
             let mut x = x.borrow_mut();
             x.int32()
         })
@@ -27,7 +179,7 @@ impl ThreadLocal {
 
     pub fn uint32() -> u32 {
         THREAD_RAND.with(|x| {
-            let x: &std::cell::RefCell<Rand<RngSource>> = x;
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
             let mut x = x.borrow_mut();
             x.uint32()
         })
@@ -35,15 +187,39 @@ impl ThreadLocal {
 
     pub fn int32n(n: i32) -> i32 {
         THREAD_RAND.with(|x| {
-            let x: &std::cell::RefCell<Rand<RngSource>> = x;
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
             let mut x = x.borrow_mut();
             x.int32n(n)
         })
     }
 
+    pub fn try_int32n(n: i32) -> Result<i32, RandError> {
+        THREAD_RAND.with(|x| {
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
+            let mut x = x.borrow_mut();
+            x.try_int32n(n)
+        })
+    }
+
+    pub fn uint32n(n: u32) -> u32 {
+        THREAD_RAND.with(|x| {
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
+            let mut x = x.borrow_mut();
+            x.uint32n(n)
+        })
+    }
+
+    pub fn try_uint32n(n: u32) -> Result<u32, RandError> {
+        THREAD_RAND.with(|x| {
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
+            let mut x = x.borrow_mut();
+            x.try_uint32n(n)
+        })
+    }
+
     pub fn int64() -> i64 {
         THREAD_RAND.with(|x| {
-            let x: &std::cell::RefCell<Rand<RngSource>> = x;
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
             let mut x = x.borrow_mut();
             x.int64()
         })
@@ -51,33 +227,132 @@ impl ThreadLocal {
 
     pub fn uint64() -> u64 {
         THREAD_RAND.with(|x| {
-            let x: &std::cell::RefCell<Rand<RngSource>> = x;
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
             let mut x = x.borrow_mut();
             x.uint64()
         })
     }
 
+    pub fn fill_bytes(buf: &mut [u8]) {
+        THREAD_RAND.with(|x| {
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
+            let mut x = x.borrow_mut();
+            x.fill_bytes(buf)
+        })
+    }
+
     pub fn int64n(n: i64) -> i64 {
         THREAD_RAND.with(|x| {
-            let x: &std::cell::RefCell<Rand<RngSource>> = x;
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
             let mut x = x.borrow_mut();
             x.int64n(n)
         })
     }
 
-    pub fn shuffle<T>(array: &mut Vec<T>) {
+    pub fn try_int64n(n: i64) -> Result<i64, RandError> {
+        THREAD_RAND.with(|x| {
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
+            let mut x = x.borrow_mut();
+            x.try_int64n(n)
+        })
+    }
+
+    pub fn uint64n(n: u64) -> u64 {
+        THREAD_RAND.with(|x| {
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
+            let mut x = x.borrow_mut();
+            x.uint64n(n)
+        })
+    }
+
+    pub fn try_uint64n(n: u64) -> Result<u64, RandError> {
+        THREAD_RAND.with(|x| {
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
+            let mut x = x.borrow_mut();
+            x.try_uint64n(n)
+        })
+    }
+
+    pub fn range<T, R>(range: R) -> T
+    where
+        R: SampleRange<T>,
+    {
+        THREAD_RAND.with(|x| {
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
+            let mut x = x.borrow_mut();
+            x.range(range)
+        })
+    }
+
+    pub fn try_range<T, R>(range: R) -> Result<T, RandError>
+    where
+        R: SampleRange<T>,
+    {
+        THREAD_RAND.with(|x| {
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
+            let mut x = x.borrow_mut();
+            x.try_range(range)
+        })
+    }
+
+    pub fn norm_float64() -> f64 {
+        THREAD_RAND.with(|x| {
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
+            let mut x = x.borrow_mut();
+            x.norm_float64()
+        })
+    }
+
+    pub fn exp_float64() -> f64 {
+        THREAD_RAND.with(|x| {
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
+            let mut x = x.borrow_mut();
+            x.exp_float64()
+        })
+    }
+
+    pub fn perm(n: usize) -> Vec<usize> {
+        THREAD_RAND.with(|x| {
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
+            let mut x = x.borrow_mut();
+            x.perm(n)
+        })
+    }
+
+    pub fn shuffle<T>(array: &mut [T]) {
         THREAD_RAND.with(|x| {
-            let x: &std::cell::RefCell<Rand<RngSource>> = x;
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
             let mut x = x.borrow_mut();
             x.shuffle(array);
         })
     }
+
+    pub fn shuffle_with<F>(n: usize, swap: F)
+    where
+        F: FnMut(usize, usize),
+    {
+        THREAD_RAND.with(|x| {
+            let x: &std::cell::RefCell<Rand<ThreadSource>> = x;
+            let mut x = x.borrow_mut();
+            x.shuffle_with(n, swap);
+        })
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::ThreadLocal;
+    use super::{new_source, ThreadLocal, ThreadSource};
+    use std::sync::{Mutex, MutexGuard};
     use std::thread;
+
+    // Held by tests that flip the process-wide modes, which would
+    // otherwise overlap when the harness runs them in parallel.
+    static MODES: Mutex<()> = Mutex::new(());
+
+    fn lock_modes() -> MutexGuard<'static, ()> {
+        MODES.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
     #[test]
     fn example_thread_local() {
         let mut handles = vec![];
@@ -98,4 +373,36 @@ mod test {
             h.join().unwrap();
         }
     }
+
+    #[test]
+    fn auto_seed() {
+        let _modes = lock_modes();
+        let first = || thread::spawn(ThreadLocal::int64).join().unwrap();
+        let (a, b) = (first(), first());
+        assert_ne!(a, b);
+
+        ThreadLocal::set_deterministic(true);
+        let (a, b) = (first(), first());
+        ThreadLocal::set_deterministic(false);
+        assert_eq!(5577006791947779410, a);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn reseeding() {
+        let _modes = lock_modes();
+        let reseeding = || {
+            thread::spawn(|| {
+                ThreadLocal::set_reseeding(true);
+                let reseeding = matches!(new_source(), ThreadSource::Reseeding(_));
+                (reseeding, ThreadLocal::int64())
+            })
+            .join()
+            .unwrap()
+        };
+        let (a, b) = (reseeding(), reseeding());
+        ThreadLocal::set_reseeding(false);
+        assert!(a.0 && b.0);
+        assert_ne!(a.1, b.1);
+    }
 }
@@ -0,0 +1,360 @@
+use crate::error::RandError;
+use crate::reader;
+use crate::source::Source;
+use crate::splitmix::SeedExpander;
+
+/// A Xoshiro256StarStar is a xoshiro256** generator with 256 bits of
+/// internal state and a period of 2^256 - 1.
+///
+/// Its jump and long_jump methods split one seeded stream into
+/// non-overlapping subsequences, so every thread can own a source
+/// instead of sharing a `LockedSource`.
+///
+/// See "Scrambled Linear Pseudorandom Number Generators"
+/// (Blackman & Vigna, 2018)
+/// https://prng.di.unimi.it/
+///
+/// # Examples
+///
+/// ```no_run
+/// use srand::{Rand, Xoshiro256StarStar};
+///
+/// fn main() {
+///     let mut src = Xoshiro256StarStar::new(1);
+///     let mut handles = vec![];
+///     for i in 0..4 {
+///         let mut r: Rand<_> = Rand::new(src.clone());
+///         src.jump();
+///         let h = std::thread::spawn(move || {
+///             println!("thread: {}, {}", i, r.int64());
+///         });
+///         handles.push(h);
+///     }
+///     for h in handles {
+///         h.join().unwrap();
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Xoshiro256State")
+)]
+pub struct Xoshiro256StarStar {
+    s: [u64; 4],
+}
+
+impl Xoshiro256StarStar {
+    /// Creates a new Xoshiro256StarStar whose state is expanded from seed.
+    pub fn new(seed: i64) -> Xoshiro256StarStar {
+        let mut src = Xoshiro256StarStar { s: [0; 4] };
+        src.seed(seed);
+        src
+    }
+
+    /// Creates a new Xoshiro256StarStar with the given state.
+    /// Requirements: the state is not everywhere zero, otherwise
+    /// `RandError::InvalidArgument` is returned.
+    pub fn from_state(s: [u64; 4]) -> Result<Xoshiro256StarStar, RandError> {
+        // An all-zero state only ever produces zeros.
+        if s == [0; 4] {
+            return Err(RandError::InvalidArgument(
+                "invalid Xoshiro256StarStar state",
+            ));
+        }
+        Ok(Xoshiro256StarStar { s })
+    }
+
+    /// Creates a new Xoshiro256StarStar seeded from the system's source
+    /// of randomness.
+    pub fn from_entropy() -> Result<Xoshiro256StarStar, RandError> {
+        let mut s = [0u64; 4];
+        while s == [0; 4] {
+            reader::read_words(&mut s)?;
+        }
+        Xoshiro256StarStar::from_state(s)
+    }
+
+    /// Advances the generator by 2^128 calls to uint64. It can be used
+    /// to generate 2^128 non-overlapping subsequences for parallel
+    /// computations.
+    pub fn jump(&mut self) {
+        self.jump_by(&[
+            0x180ec6d33cfd0aba,
+            0xd5a61266f0c9392c,
+            0xa9582618e03fc9aa,
+            0x39abdc4529b1661c,
+        ]);
+    }
+
+    /// Advances the generator by 2^192 calls to uint64. It can be used
+    /// to generate 2^64 starting points, from each of which jump will
+    /// generate 2^64 non-overlapping subsequences for parallel
+    /// distributed computations.
+    pub fn long_jump(&mut self) {
+        self.jump_by(&[
+            0x76e15d3efefdcbbf,
+            0xc5004e441c522fb3,
+            0x77710069854ee241,
+            0x39109bb02acbe635,
+        ]);
+    }
+
+    fn jump_by(&mut self, poly: &[u64; 4]) {
+        let mut s = [0u64; 4];
+        for word in poly {
+            for b in 0..64 {
+                if word & (1 << b) != 0 {
+                    for (acc, x) in s.iter_mut().zip(self.s.iter()) {
+                        *acc ^= x;
+                    }
+                }
+                self.uint64();
+            }
+        }
+        self.s = s;
+    }
+}
+
+impl Source for Xoshiro256StarStar {
+    /// Expands seed into the full 256-bit state with SplitMix64.
+    fn seed(&mut self, seed: i64) {
+        SeedExpander::new(seed as u64).fill(&mut self.s);
+    }
+
+    fn uint64(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+        result
+    }
+
+    fn int64(&mut self) -> i64 {
+        (self.uint64() >> 1) as i64
+    }
+}
+
+/// A Xoroshiro128Plus is a xoroshiro128+ generator with 128 bits of
+/// internal state and a period of 2^128 - 1.
+///
+/// It is the fastest of the generators here, but its lowest bits are
+/// weak, so int64 and everything built on it use the upper bits.
+///
+/// See "Scrambled Linear Pseudorandom Number Generators"
+/// (Blackman & Vigna, 2018)
+/// https://prng.di.unimi.it/
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Xoroshiro128State")
+)]
+pub struct Xoroshiro128Plus {
+    s: [u64; 2],
+}
+
+impl Xoroshiro128Plus {
+    /// Creates a new Xoroshiro128Plus whose state is expanded from seed.
+    pub fn new(seed: i64) -> Xoroshiro128Plus {
+        let mut src = Xoroshiro128Plus { s: [0; 2] };
+        src.seed(seed);
+        src
+    }
+
+    /// Creates a new Xoroshiro128Plus with the given state.
+    /// Requirements: the state is not everywhere zero, otherwise
+    /// `RandError::InvalidArgument` is returned.
+    pub fn from_state(s: [u64; 2]) -> Result<Xoroshiro128Plus, RandError> {
+        if s == [0; 2] {
+            return Err(RandError::InvalidArgument("invalid Xoroshiro128Plus state"));
+        }
+        Ok(Xoroshiro128Plus { s })
+    }
+
+    /// Creates a new Xoroshiro128Plus seeded from the system's source
+    /// of randomness.
+    pub fn from_entropy() -> Result<Xoroshiro128Plus, RandError> {
+        let mut s = [0u64; 2];
+        while s == [0; 2] {
+            reader::read_words(&mut s)?;
+        }
+        Xoroshiro128Plus::from_state(s)
+    }
+
+    /// Advances the generator by 2^64 calls to uint64. It can be used
+    /// to generate 2^64 non-overlapping subsequences for parallel
+    /// computations.
+    pub fn jump(&mut self) {
+        self.jump_by(&[0xdf900294d8f554a5, 0x170865df4b3201fc]);
+    }
+
+    /// Advances the generator by 2^96 calls to uint64. It can be used
+    /// to generate 2^32 starting points, from each of which jump will
+    /// generate 2^32 non-overlapping subsequences for parallel
+    /// distributed computations.
+    pub fn long_jump(&mut self) {
+        self.jump_by(&[0xd2a98b26625eee7b, 0xdddf9b1090aa7ac1]);
+    }
+
+    fn jump_by(&mut self, poly: &[u64; 2]) {
+        let mut s = [0u64; 2];
+        for word in poly {
+            for b in 0..64 {
+                if word & (1 << b) != 0 {
+                    s[0] ^= self.s[0];
+                    s[1] ^= self.s[1];
+                }
+                self.uint64();
+            }
+        }
+        self.s = s;
+    }
+}
+
+impl Source for Xoroshiro128Plus {
+    /// Expands seed into the full 128-bit state with SplitMix64.
+    fn seed(&mut self, seed: i64) {
+        SeedExpander::new(seed as u64).fill(&mut self.s);
+    }
+
+    fn uint64(&mut self) -> u64 {
+        let s0 = self.s[0];
+        let mut s1 = self.s[1];
+        let result = s0.wrapping_add(s1);
+        s1 ^= s0;
+        self.s[0] = s0.rotate_left(24) ^ s1 ^ (s1 << 16);
+        self.s[1] = s1.rotate_left(37);
+        result
+    }
+
+    fn int64(&mut self) -> i64 {
+        (self.uint64() >> 1) as i64
+    }
+}
+
+/// The serialized form of a Xoshiro256StarStar, checked before use.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct Xoshiro256State {
+    s: [u64; 4],
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<Xoshiro256State> for Xoshiro256StarStar {
+    type Error = RandError;
+
+    fn try_from(state: Xoshiro256State) -> Result<Xoshiro256StarStar, RandError> {
+        Xoshiro256StarStar::from_state(state.s)
+    }
+}
+
+/// The serialized form of a Xoroshiro128Plus, checked before use.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct Xoroshiro128State {
+    s: [u64; 2],
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<Xoroshiro128State> for Xoroshiro128Plus {
+    type Error = RandError;
+
+    fn try_from(state: Xoroshiro128State) -> Result<Xoroshiro128Plus, RandError> {
+        Xoroshiro128Plus::from_state(state.s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Xoroshiro128Plus, Xoshiro256StarStar};
+    use crate::source::Source;
+
+    #[test]
+    fn xoshiro256starstar() {
+        let expected: Vec<u64> = vec![
+            11520,
+            0,
+            1509978240,
+            1215971899390074240,
+            1216172134540287360,
+            607988272756665600,
+            16172922978634559625,
+            8476171486693032832,
+            10595114339597558777,
+            2904607092377533576,
+        ];
+        let mut src = Xoshiro256StarStar::from_state([1, 2, 3, 4]).unwrap();
+        let mut get = vec![];
+        for _i in 0..10 {
+            get.push(src.uint64());
+        }
+        assert_eq!(expected, get);
+
+        // jump and long_jump match 2^128 and 2^192 applications of the
+        // state transition, computed by squaring its matrix over GF(2).
+        let mut src = Xoshiro256StarStar::from_state([1, 2, 3, 4]).unwrap();
+        src.jump();
+        assert_eq!(
+            [
+                10122426448480695249,
+                8079205330032121950,
+                7289065458748526725,
+                9477464255293849680
+            ],
+            src.s
+        );
+        let mut src = Xoshiro256StarStar::from_state([1, 2, 3, 4]).unwrap();
+        src.long_jump();
+        assert_eq!(
+            [
+                678511610814637056,
+                15850499779492529430,
+                6002989639035333134,
+                3559352929785830385
+            ],
+            src.s
+        );
+
+        assert!(Xoshiro256StarStar::from_state([0; 4]).is_err());
+    }
+
+    #[test]
+    fn xoroshiro128plus() {
+        let expected: Vec<u64> = vec![
+            3,
+            412333834243,
+            2360170716294286339,
+            9295852285959843169,
+            2797080929874688578,
+            6019711933173041966,
+            3076529664176959358,
+            3521761819100106140,
+            7493067640054542992,
+            920801338098114767,
+        ];
+        let mut src = Xoroshiro128Plus::from_state([1, 2]).unwrap();
+        let mut get = vec![];
+        for _i in 0..10 {
+            get.push(src.uint64());
+        }
+        assert_eq!(expected, get);
+
+        // jump and long_jump match 2^64 and 2^96 applications of the
+        // state transition, computed by squaring its matrix over GF(2).
+        let mut src = Xoroshiro128Plus::from_state([1, 2]).unwrap();
+        src.jump();
+        assert_eq!([7420758724034209717, 9442990532527272306], src.s);
+        let mut src = Xoroshiro128Plus::from_state([1, 2]).unwrap();
+        src.long_jump();
+        assert_eq!([4387707342976528954, 3072119776036644419], src.s);
+
+        assert!(Xoroshiro128Plus::from_state([0; 2]).is_err());
+    }
+}
@@ -0,0 +1,126 @@
+use crate::error::RandError;
+use crate::rand::Rand;
+use crate::source::Source;
+
+/// A Zipf generates Zipf distributed variates.
+///
+/// The generator generates values k ∈ [0, imax] such that P(k) is
+/// proportional to (v + k) ** (-s).
+///
+/// See "Rejection-Inversion to Generate Variates from Monotone Discrete
+/// Distributions" (Hörmann & Derflinger, 1996)
+/// http://eeyore.wu-wien.ac.at/papers/96-04-04.wh-der.ps.gz
+pub struct Zipf<'a, S>
+where
+    S: Source,
+{
+    r: &'a mut Rand<S>,
+    imax: f64,
+    v: f64,
+    q: f64,
+    s: f64,
+    one_minus_q: f64,
+    one_minus_q_inv: f64,
+    hxm: f64,
+    hx0_minus_hxm: f64,
+}
+
+impl<'a, S> Zipf<'a, S>
+where
+    S: Source,
+{
+    /// Creates a new Zipf variate generator drawing from r.
+    /// Requirements: s > 1 and v >= 1, otherwise
+    /// `RandError::InvalidArgument` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use srand::{Rand, RngSource, Zipf};
+    ///
+    /// fn main() -> Result<(), srand::RandError> {
+    ///     let mut r: Rand<_> = Rand::new(RngSource::new(1));
+    ///     let mut z = Zipf::new(&mut r, 1.1, 1.0, 1000)?;
+    ///     println!("n: {}", z.uint64());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(r: &'a mut Rand<S>, s: f64, v: f64, imax: u64) -> Result<Zipf<'a, S>, RandError> {
+        if !(s > 1.0 && v >= 1.0) {
+            return Err(RandError::InvalidArgument(
+                "zipf: requires s > 1 and v >= 1",
+            ));
+        }
+        let mut z = Zipf {
+            r,
+            imax: imax as f64,
+            v,
+            q: s,
+            s: 0.0,
+            one_minus_q: 1.0 - s,
+            one_minus_q_inv: 1.0 / (1.0 - s),
+            hxm: 0.0,
+            hx0_minus_hxm: 0.0,
+        };
+        z.hxm = z.h(z.imax + 0.5);
+        z.hx0_minus_hxm = z.h(0.5) - (z.v.ln() * -z.q).exp() - z.hxm;
+        z.s = 1.0 - z.hinv(z.h(1.5) - (-z.q * (z.v + 1.0).ln()).exp());
+        Ok(z)
+    }
+
+    fn h(&self, x: f64) -> f64 {
+        (self.one_minus_q * (self.v + x).ln()).exp() * self.one_minus_q_inv
+    }
+
+    fn hinv(&self, x: f64) -> f64 {
+        (self.one_minus_q_inv * (self.one_minus_q * x).ln()).exp() - self.v
+    }
+
+    /// Returns a value drawn from the Zipf distribution described
+    /// by the Zipf object.
+    pub fn uint64(&mut self) -> u64 {
+        let mut k;
+        loop {
+            // r on [0,1]
+            let r = self.r.float64();
+            let ur = self.hxm + r * self.hx0_minus_hxm;
+            let x = self.hinv(ur);
+            k = (x + 0.5).floor();
+            if k - x <= self.s {
+                break;
+            }
+            if ur >= self.h(k + 0.5) - (-(k + self.v).ln() * self.q).exp() {
+                break;
+            }
+        }
+        k as u64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Zipf;
+    use crate::rand::Rand;
+    use crate::source::RngSource;
+
+    #[test]
+    fn examples() {
+        let mut r: Rand<_> = Rand::new(RngSource::new(1));
+        assert!(Zipf::new(&mut r, 1.0, 1.0, 100).is_err());
+        assert!(Zipf::new(&mut r, 1.5, 0.5, 100).is_err());
+        assert!(Zipf::new(&mut r, f64::NAN, 1.0, 100).is_err());
+        assert!(Zipf::new(&mut r, 1.5, f64::NAN, 100).is_err());
+
+        let expected: Vec<u64> = vec![
+            2, 0, 1, 6, 7, 1, 64, 36, 52, 15, 4, 0, 25, 9, 13, 5, 16, 15, 1, 24, 27, 10, 3, 0, 15,
+            15, 1, 26, 0, 1, 4, 83, 35, 2, 0, 59, 2, 67, 1, 15, 32, 3, 3, 17, 7, 4, 20, 16, 0, 10,
+        ];
+        let mut r: Rand<_> = Rand::new(RngSource::new(1));
+        let mut z = Zipf::new(&mut r, 1.1, 1.0, 100).unwrap();
+        let mut get = vec![];
+        for _i in 0..50 {
+            get.push(z.uint64());
+        }
+        assert_eq!(expected, get);
+    }
+}